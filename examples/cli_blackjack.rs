@@ -5,7 +5,7 @@
 use std::io::{self, Write};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use bjrs::{Card, DoubleOption, Game, GameOptions, GameState, Hand, HandStatus, Suit};
+use bjrs::{Card, Chips, Game, GameOptions, GameState, Hand, Suit};
 
 fn main() {
     println!("Blackjack CLI example (type 'q' to quit)");
@@ -17,10 +17,10 @@ fn main() {
     let options = GameOptions::default();
     let game = Game::new(options, seed);
 
-    let player_id = game.join(500);
+    let player_id = game.join(Chips::new(500));
 
     loop {
-        let money = game.get_money(player_id).unwrap_or(0);
+        let money = game.get_money(player_id).unwrap_or_default().get();
         if money == 0 {
             println!("You are out of money. Game over.");
             break;
@@ -32,11 +32,11 @@ fn main() {
 
         game.start_betting();
 
-        let Some(bet) = prompt_usize(&format!("Bet amount (1-{money}, 0 to quit): ")) else {
+        let Some(bet) = prompt_chips(&format!("Bet amount (1-{money}, 0 to quit): ")) else {
             break;
         };
 
-        if bet == 0 {
+        if bet.is_zero() {
             println!("Goodbye.");
             break;
         }
@@ -56,7 +56,7 @@ fn main() {
             println!("Dealer shows an Ace. Insurance offered.");
             match prompt_line("Take insurance? (y/n): ").as_str() {
                 "y" | "yes" => match game.take_insurance(player_id) {
-                    Ok(amount) => println!("Insurance bet placed: {amount}"),
+                    Ok(amount) => println!("Insurance bet placed: {}", amount.get()),
                     Err(err) => println!("Insurance error: {err:?}"),
                 },
                 _ => {
@@ -77,11 +77,6 @@ fn main() {
             }
         }
 
-        // If there is no active player turn (e.g., initial blackjack), move to dealer.
-        if *game.state.lock() == GameState::PlayerTurn && game.current_player().is_none() {
-            *game.state.lock() = GameState::DealerTurn;
-        }
-
         while *game.state.lock() == GameState::PlayerTurn {
             print_table(&game, player_id);
 
@@ -125,9 +120,9 @@ fn main() {
                     println!("Round complete.");
                     for player in result.players {
                         if player.player_id == player_id {
-                            println!("Payout: {} (net {})", player.total_payout, player.net);
-                            if player.insurance_bet > 0 {
-                                println!("Insurance payout: {}", player.insurance_payout);
+                            println!("Payout: {} (net {})", player.total_payout.get(), player.net);
+                            if !player.insurance_bet.is_zero() {
+                                println!("Insurance payout: {}", player.insurance_payout.get());
                             }
                         }
                     }
@@ -153,14 +148,14 @@ fn prompt_line(prompt: &str) -> String {
     input.trim().to_lowercase()
 }
 
-fn prompt_usize(prompt: &str) -> Option<usize> {
+fn prompt_chips(prompt: &str) -> Option<Chips> {
     loop {
         let input = prompt_line(prompt);
         if input == "q" || input == "quit" {
             return None;
         }
-        match input.parse::<usize>() {
-            Ok(value) => return Some(value),
+        match input.parse::<u64>() {
+            Ok(value) => return Some(Chips::new(value)),
             Err(_) => println!("Please enter a number."),
         }
     }
@@ -189,7 +184,7 @@ fn print_table(game: &Game, player_id: u8) {
             index,
             format_hand(hand),
             hand.value(),
-            hand.bet(),
+            hand.bet().get(),
             hand.status()
         );
     }
@@ -214,7 +209,7 @@ fn print_table_final(game: &Game, player_id: u8) {
             index,
             format_hand(hand),
             hand.value(),
-            hand.bet(),
+            hand.bet().get(),
             hand.status()
         );
     }
@@ -222,7 +217,7 @@ fn print_table_final(game: &Game, player_id: u8) {
 }
 
 fn format_actions(game: &Game, player_id: u8) -> String {
-    let availability = available_actions(game, player_id);
+    let availability = game.available_actions(player_id, game.current_turn().hand_index);
     let mut parts = Vec::new();
     parts.push(format_action("hit", "h", availability.hit));
     parts.push(format_action("stand", "s", availability.stand));
@@ -245,94 +240,6 @@ fn colorize(text: &str, code: &str) -> String {
     format!("\u{1b}[{code}m{text}\u{1b}[0m")
 }
 
-struct ActionAvailability {
-    hit: bool,
-    stand: bool,
-    double: bool,
-    split: bool,
-    surrender: bool,
-}
-
-fn available_actions(game: &Game, player_id: u8) -> ActionAvailability {
-    if *game.state.lock() != GameState::PlayerTurn {
-        return ActionAvailability {
-            hit: false,
-            stand: false,
-            double: false,
-            split: false,
-            surrender: false,
-        };
-    }
-
-    if game.current_player() != Some(player_id) {
-        return ActionAvailability {
-            hit: false,
-            stand: false,
-            double: false,
-            split: false,
-            surrender: false,
-        };
-    }
-
-    let hands = game.get_hands(player_id).unwrap_or_default();
-    let turn = game.current_turn();
-    let Some(hand) = hands.get(turn.hand_index) else {
-        return ActionAvailability {
-            hit: false,
-            stand: false,
-            double: false,
-            split: false,
-            surrender: false,
-        };
-    };
-
-    if hand.status() != HandStatus::Active {
-        return ActionAvailability {
-            hit: false,
-            stand: false,
-            double: false,
-            split: false,
-            surrender: false,
-        };
-    }
-
-    let money = game.get_money(player_id).unwrap_or(0);
-    let bet = hand.bet();
-    let has_funds_for_double = money >= bet;
-    let has_funds_for_split = money >= bet;
-
-    let can_double_value = match game.options.double {
-        DoubleOption::Any => true,
-        DoubleOption::NineOrTen => hand.value() == 9 || hand.value() == 10,
-        DoubleOption::NineThrough11 => (9..=11).contains(&hand.value()),
-        DoubleOption::NineThrough15 => (9..=15).contains(&hand.value()),
-        DoubleOption::None => false,
-        _ => panic!("unhandled double option"),
-    };
-
-    let can_double = hand.len() == 2
-        && (!hand.is_from_split() || game.options.double_after_split)
-        && can_double_value
-        && has_funds_for_double;
-
-    let is_ace = hand.cards().first().is_some_and(|c| c.rank == 1);
-    let max_splits_reached = hands.len() > game.options.split as usize;
-    let can_split = hand.can_split()
-        && !max_splits_reached
-        && has_funds_for_split
-        && !(is_ace && hand.is_from_split() && game.options.split_aces_only_once);
-
-    let can_surrender = game.options.surrender && hand.len() == 2 && !hand.is_from_split();
-
-    ActionAvailability {
-        hit: true,
-        stand: true,
-        double: can_double,
-        split: can_split,
-        surrender: can_surrender,
-    }
-}
-
 fn format_dealer(dealer: &bjrs::DealerHand) -> String {
     if dealer.cards().is_empty() {
         return "(no cards)".to_string();
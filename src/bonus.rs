@@ -0,0 +1,69 @@
+//! Spanish 21 bonus payouts.
+
+use crate::card::Suit;
+use crate::hand::{Hand, HandStatus};
+use crate::payout::Payout;
+
+/// Returns the Spanish 21 bonus ratio a hand earns, or `None` if it earns
+/// no bonus.
+///
+/// Only a 21 that was not doubled qualifies:
+///
+/// | Hand | Mixed suits | Same suit | All spades |
+/// |------|-------------|-----------|------------|
+/// | 6-7-8 or 7-7-7 | 3:2 | 2:1 | 3:1 |
+/// | Five cards | 3:2 | 3:2 | 3:2 |
+/// | Six cards | 2:1 | 2:1 | 2:1 |
+/// | Seven or more cards | 3:1 | 3:1 | 3:1 |
+///
+/// The ratio is winnings to stake; the stake is returned on top.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, Chips, Hand, Payout, Suit, spanish_21_bonus};
+///
+/// let mut hand = Hand::new(Chips::new(10));
+/// hand.add_card(Card::new(Suit::Hearts, 6));
+/// hand.add_card(Card::new(Suit::Hearts, 7));
+/// hand.add_card(Card::new(Suit::Hearts, 8));
+/// assert_eq!(spanish_21_bonus(&hand), Some(Payout::new(2, 1)));
+///
+/// let mut hand = Hand::new(Chips::new(10));
+/// hand.add_card(Card::new(Suit::Hearts, 9));
+/// hand.add_card(Card::new(Suit::Clubs, 12));
+/// hand.add_card(Card::new(Suit::Spades, 2));
+/// assert_eq!(spanish_21_bonus(&hand), None);
+/// ```
+#[must_use]
+pub fn spanish_21_bonus(hand: &Hand) -> Option<Payout> {
+    if hand.value() != 21
+        || hand.is_doubled()
+        || matches!(hand.status(), HandStatus::Bust | HandStatus::Surrendered)
+    {
+        return None;
+    }
+
+    match hand.len() {
+        3 => {
+            let cards = hand.cards();
+            let mut ranks = [cards[0].rank, cards[1].rank, cards[2].rank];
+            ranks.sort_unstable();
+            if ranks != [6, 7, 8] && ranks != [7, 7, 7] {
+                return None;
+            }
+            let suit = cards[0].suit;
+            if cards.iter().all(|card| card.suit == Suit::Spades) {
+                Some(Payout::new(3, 1))
+            } else if cards.iter().all(|card| card.suit == suit) {
+                Some(Payout::new(2, 1))
+            } else {
+                Some(Payout::new(3, 2))
+            }
+        }
+        5 => Some(Payout::new(3, 2)),
+        6 => Some(Payout::new(2, 1)),
+        len if len >= 7 => Some(Payout::new(3, 1)),
+        _ => None,
+    }
+}
@@ -1,7 +1,10 @@
 //! Card types and deck utilities.
 
 /// Card suit.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Suits are ordered as declared, which is also [`Suit::ALL`] order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suit {
     /// Hearts.
     Hearts,
@@ -13,8 +16,70 @@ pub enum Suit {
     Spades,
 }
 
+impl Suit {
+    /// All four suits, in deck order.
+    pub const ALL: [Self; 4] = [Self::Hearts, Self::Diamonds, Self::Clubs, Self::Spades];
+
+    /// Returns an iterator over all four suits, in deck order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the suit's color: hearts and diamonds are red, clubs and
+    /// spades are black.
+    #[must_use]
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Hearts | Self::Diamonds => Color::Red,
+            Self::Clubs | Self::Spades => Color::Black,
+        }
+    }
+
+    /// Returns whether the suit is red (hearts or diamonds).
+    #[must_use]
+    pub const fn is_red(self) -> bool {
+        matches!(self.color(), Color::Red)
+    }
+
+    /// Returns whether the suit is black (clubs or spades).
+    #[must_use]
+    pub const fn is_black(self) -> bool {
+        matches!(self.color(), Color::Black)
+    }
+}
+
+/// Suit color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    /// Hearts and diamonds.
+    Red,
+    /// Clubs and spades.
+    Black,
+}
+
 /// A playing card.
+///
+/// Cards are ordered by blackjack value (aces count as 11, so they sort
+/// highest), then by rank, then by suit.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, Suit};
+///
+/// let mut cards = vec![
+///     Card::new(Suit::Spades, 1),
+///     Card::new(Suit::Hearts, 13),
+///     Card::new(Suit::Clubs, 10),
+///     Card::new(Suit::Hearts, 2),
+/// ];
+/// cards.sort();
+/// assert_eq!(cards[0], Card::new(Suit::Hearts, 2));
+/// assert_eq!(cards[3], Card::new(Suit::Spades, 1));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card {
     /// The suit of the card.
     pub suit: Suit,
@@ -31,7 +96,84 @@ impl Card {
     pub const fn new(suit: Suit, rank: u8) -> Self {
         Self { suit, rank }
     }
+
+    /// Returns the card's blackjack value: 11 for an ace, 10 for face cards,
+    /// and the rank otherwise. Ranks outside 1..=13 are worth 0.
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        match self.rank {
+            1 => 11,
+            2..=10 => self.rank,
+            11..=13 => 10,
+            _ => 0,
+        }
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value()
+            .cmp(&other.value())
+            .then(self.rank.cmp(&other.rank))
+            .then(self.suit.cmp(&other.suit))
+    }
 }
 
 /// Number of cards per deck.
 pub const DECK_SIZE: usize = 52;
+
+/// Number of cards per Spanish deck (a standard deck without the tens).
+pub const SPANISH_DECK_SIZE: usize = 48;
+
+/// Returns one standard 52-card deck, unshuffled.
+///
+/// Cards are ordered by suit (in [`Suit::ALL`] order), then by rank from ace
+/// to king.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, DECK_SIZE, Suit, standard_deck};
+///
+/// let deck = standard_deck();
+/// assert_eq!(deck.len(), DECK_SIZE);
+/// assert_eq!(deck[0], Card::new(Suit::Hearts, 1));
+/// assert_eq!(deck[DECK_SIZE - 1], Card::new(Suit::Spades, 13));
+/// ```
+#[must_use]
+pub fn standard_deck() -> [Card; DECK_SIZE] {
+    #[expect(clippy::cast_possible_truncation, reason = "ranks are at most 13")]
+    core::array::from_fn(|index| Card::new(Suit::ALL[index / 13], (index % 13) as u8 + 1))
+}
+
+/// Returns one Spanish deck, unshuffled: a standard deck with the four
+/// ten-spot cards removed. Jacks, queens and kings remain.
+///
+/// Cards are ordered as in [`standard_deck`].
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, SPANISH_DECK_SIZE, Suit, spanish_deck};
+///
+/// let deck = spanish_deck();
+/// assert_eq!(deck.len(), SPANISH_DECK_SIZE);
+/// assert!(deck.iter().all(|card| card.rank != 10));
+/// assert_eq!(deck[9], Card::new(Suit::Hearts, 11));
+/// ```
+#[must_use]
+pub fn spanish_deck() -> [Card; SPANISH_DECK_SIZE] {
+    #[expect(clippy::cast_possible_truncation, reason = "ranks are at most 13")]
+    core::array::from_fn(|index| {
+        let rank = (index % 12) as u8 + 1;
+        // Skip the ten
+        let rank = if rank >= 10 { rank + 1 } else { rank };
+        Card::new(Suit::ALL[index / 12], rank)
+    })
+}
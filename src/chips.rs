@@ -0,0 +1,201 @@
+//! Amounts of money.
+
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// An amount of money: a bankroll, a bet, a payout or a table limit.
+///
+/// Chips are backed by a `u64`, so a game behaves the same on 32-bit wasm
+/// and 64-bit servers. The unit is up to the caller; count cents to play
+/// with fractional stakes.
+///
+/// Arithmetic never wraps. The `checked_*` methods return `None` on
+/// overflow or underflow, and the `+`, `-` and `*` operators panic instead.
+/// The game itself never panics on overflow: payouts and refunds that would
+/// pass [`Chips::MAX`] stop there.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::Chips;
+///
+/// let bankroll = Chips::new(100);
+/// let bet = Chips::new(25);
+/// assert_eq!(bankroll - bet, Chips::new(75));
+/// assert_eq!(bet.checked_sub(bankroll), None);
+/// assert_eq!(bet.signed_sub(bankroll), -75);
+/// assert_eq!(u64::from(bet * 2), 50);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Chips(u64);
+
+impl Chips {
+    /// No chips.
+    pub const ZERO: Self = Self(0);
+
+    /// The largest representable amount.
+    pub const MAX: Self = Self(u64::MAX);
+
+    /// Creates an amount of chips.
+    #[must_use]
+    pub const fn new(amount: u64) -> Self {
+        Self(amount)
+    }
+
+    /// Returns the amount as a `u64`.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns whether the amount is zero.
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs`, returning `None` if it is larger than `self`.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by a count, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+
+    /// Returns the remainder of dividing by `rhs`, or `None` if `rhs` is
+    /// zero.
+    #[must_use]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_rem(rhs.0) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by a count, stopping at [`Chips::MAX`].
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: u64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
+
+    /// Adds two amounts, stopping at [`Chips::MAX`].
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs`, stopping at zero.
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns `self - rhs` as a signed amount, clamped to the `i64` range.
+    ///
+    /// Net results (payout minus bet) use this.
+    #[must_use]
+    pub const fn signed_sub(self, rhs: Self) -> i64 {
+        let difference = self.0 as i128 - rhs.0 as i128;
+        if difference > i64::MAX as i128 {
+            i64::MAX
+        } else if difference < i64::MIN as i128 {
+            i64::MIN
+        } else {
+            difference as i64
+        }
+    }
+
+    /// Adds a signed amount, returning `None` if the result would be
+    /// negative or overflow.
+    #[must_use]
+    pub const fn checked_add_signed(self, delta: i64) -> Option<Self> {
+        match self.0.checked_add_signed(delta) {
+            Some(amount) => Some(Self(amount)),
+            None => None,
+        }
+    }
+}
+
+impl From<u64> for Chips {
+    fn from(amount: u64) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<Chips> for u64 {
+    fn from(chips: Chips) -> Self {
+        chips.0
+    }
+}
+
+impl Add for Chips {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("chip amount overflowed")
+    }
+}
+
+impl AddAssign for Chips {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Chips {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("chip amount underflowed")
+    }
+}
+
+impl SubAssign for Chips {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for Chips {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self {
+        self.checked_mul(rhs).expect("chip amount overflowed")
+    }
+}
+
+impl Sum for Chips {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Chips {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
@@ -0,0 +1,93 @@
+//! Hand comparison helpers for showdown logic.
+
+use core::cmp::Ordering;
+
+use crate::hand::{DealerHand, Hand, HandStatus};
+use crate::result::HandOutcome;
+
+/// How a tie between the player and the dealer is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Tie {
+    /// Ties push and the bet is returned (standard).
+    #[default]
+    Push,
+    /// The dealer wins ties.
+    Dealer,
+    /// The player wins ties.
+    Player,
+}
+
+impl Tie {
+    /// Returns the hand outcome for a tie under this rule.
+    #[must_use]
+    pub const fn outcome(self) -> HandOutcome {
+        match self {
+            Self::Push => HandOutcome::Push,
+            Self::Dealer => HandOutcome::Lose,
+            Self::Player => HandOutcome::Win,
+        }
+    }
+}
+
+/// Compares a player hand against the dealer using standard blackjack rules.
+///
+/// - A surrendered hand is [`HandOutcome::Surrendered`].
+/// - A busted player hand loses, even if the dealer also busts.
+/// - A Charlie is [`HandOutcome::Charlie`] unless the dealer has a natural.
+/// - A player natural beats any dealer hand except a dealer natural.
+/// - A dealer natural beats any player hand that is not a natural, including
+///   a drawn 21. A natural that does not count as a blackjack under the
+///   hand's [`BlackjackQualifier`](crate::BlackjackQualifier) ties it.
+/// - Otherwise a dealer bust or a higher total wins for the player.
+///
+/// Equal totals, and a natural against a natural, are resolved by `ties_to`.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, Chips, DealerHand, Hand, HandOutcome, Suit, Tie, compare_blackjack};
+///
+/// let mut player = Hand::new(Chips::new(10));
+/// player.add_card(Card::new(Suit::Hearts, 10));
+/// player.add_card(Card::new(Suit::Clubs, 8));
+///
+/// let mut dealer = DealerHand::new();
+/// dealer.add_card(Card::new(Suit::Spades, 9));
+/// dealer.add_card(Card::new(Suit::Diamonds, 9));
+///
+/// assert_eq!(compare_blackjack(&player, &dealer, Tie::Push), HandOutcome::Push);
+/// assert_eq!(compare_blackjack(&player, &dealer, Tie::Dealer), HandOutcome::Lose);
+/// ```
+#[must_use]
+pub fn compare_blackjack(player: &Hand, dealer: &DealerHand, ties_to: Tie) -> HandOutcome {
+    match player.status() {
+        HandStatus::Surrendered => return HandOutcome::Surrendered,
+        HandStatus::Bust => return HandOutcome::Lose,
+        HandStatus::Charlie if !dealer.is_blackjack() => return HandOutcome::Charlie,
+        HandStatus::Blackjack | HandStatus::Stand | HandStatus::Active | HandStatus::Charlie => {}
+    }
+
+    if player.is_blackjack() {
+        return if dealer.is_blackjack() {
+            ties_to.outcome()
+        } else {
+            HandOutcome::Blackjack
+        };
+    }
+
+    if dealer.is_blackjack() {
+        return if player.is_natural() {
+            ties_to.outcome()
+        } else {
+            HandOutcome::Lose
+        };
+    }
+
+    match player.beats(dealer.value()) {
+        Ordering::Greater => HandOutcome::Win,
+        Ordering::Less => HandOutcome::Lose,
+        Ordering::Equal => ties_to.outcome(),
+    }
+}
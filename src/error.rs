@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::game::GameState;
+
 /// Errors that can occur during betting.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum BetError {
@@ -17,6 +19,21 @@ pub enum BetError {
     /// Bet amount is zero.
     #[error("bet amount is zero")]
     ZeroBet,
+    /// Player has not placed a bet.
+    #[error("player has not placed a bet")]
+    NoBet,
+    /// Bet is below the table minimum.
+    #[error("bet is below the table minimum")]
+    BelowMinimum,
+    /// Bet is above the table maximum.
+    #[error("bet is above the table maximum")]
+    AboveMaximum,
+    /// Bet is not a multiple of the table's bet increment.
+    #[error("bet is not a multiple of the bet increment")]
+    InvalidIncrement,
+    /// Player already placed this side bet.
+    #[error("player already placed this side bet")]
+    SideBetAlreadyPlaced,
 }
 
 /// Errors that can occur during dealing.
@@ -31,6 +48,13 @@ pub enum DealError {
     /// Not enough cards in the shoe.
     #[error("not enough cards in the shoe")]
     NotEnoughCards,
+    /// A seated player has not placed a bet.
+    #[error("a seated player has not placed a bet")]
+    MissingBet,
+    /// The seated players or bets are inconsistent, such as a player seated
+    /// twice or a bet from a player who is not seated.
+    #[error("inconsistent player or bet state")]
+    InconsistentState,
 }
 
 /// Errors that can occur during player actions.
@@ -63,6 +87,9 @@ pub enum ActionError {
     /// Cannot surrender at this point.
     #[error("cannot surrender at this point")]
     CannotSurrender,
+    /// Cannot switch cards at this point.
+    #[error("cannot switch cards at this point")]
+    CannotSwitch,
     /// Insufficient funds for this action.
     #[error("insufficient funds for this action")]
     InsufficientFunds,
@@ -92,6 +119,42 @@ pub enum InsuranceError {
     /// Player has not placed a bet.
     #[error("player has not placed a bet")]
     NoBet,
+    /// The hand is not a blackjack.
+    #[error("hand is not a blackjack")]
+    NotBlackjack,
+    /// Insurance amount is zero or more than half the bet.
+    #[error("insurance amount must be between 1 and half the bet")]
+    InvalidAmount,
+}
+
+/// Errors that can occur when changing game options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum OptionsError {
+    /// Options can only be changed between rounds.
+    #[error("options can only be changed between rounds")]
+    InvalidState,
+    /// Penetration must be between 0 and 1.
+    #[error("penetration must be between 0 and 1")]
+    InvalidPenetration,
+    /// The shoe must hold at least one deck.
+    #[error("the shoe must hold at least one deck")]
+    NoDecks,
+    /// Payout ratios must be finite and not negative.
+    #[error("payout ratios must be finite and not negative")]
+    InvalidPayout,
+    /// Players must be dealt at least one card.
+    #[error("players must be dealt at least one card")]
+    InvalidInitialCards,
+    /// The bet increment must not be zero and the minimum bet must not
+    /// exceed the maximum.
+    #[error("invalid bet limits")]
+    InvalidBetLimits,
+    /// A Charlie must take at least three cards.
+    #[error("a charlie must take at least three cards")]
+    InvalidCharlie,
+    /// A hand must be able to hold the cards it is dealt.
+    #[error("a hand must be able to hold the cards it is dealt")]
+    InvalidMaxCards,
 }
 
 /// Errors that can occur during showdown.
@@ -103,6 +166,12 @@ pub enum ShowdownError {
     /// No cards left in the shoe.
     #[error("no cards left in the shoe")]
     NoCards,
+    /// Player has no hands in this round.
+    #[error("player has no hands in this round")]
+    PlayerNotFound,
+    /// Player has already been settled this round.
+    #[error("player has already been settled this round")]
+    AlreadySettled,
 }
 
 /// Errors that can occur during reshuffling.
@@ -112,3 +181,87 @@ pub enum ReshuffleError {
     #[error("invalid game state for reshuffling")]
     InvalidState,
 }
+
+/// Errors that can occur when adjusting a player's funds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum FundsError {
+    /// Invalid game state for adjusting funds.
+    #[error("invalid game state for adjusting funds")]
+    InvalidState,
+    /// Player not found.
+    #[error("player not found")]
+    PlayerNotFound,
+    /// The adjustment would take the balance below zero.
+    #[error("insufficient funds")]
+    InsufficientFunds,
+    /// The adjustment would overflow the balance.
+    #[error("balance overflow")]
+    Overflow,
+}
+
+/// Errors that can occur when changing the game state directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum StateError {
+    /// The game cannot move between these states.
+    #[error("illegal state transition from {from:?} to {to:?}")]
+    IllegalTransition {
+        /// The state the game is in.
+        from: GameState,
+        /// The state that was requested.
+        to: GameState,
+    },
+}
+
+/// Errors that can occur when changing the shoe directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ShoeError {
+    /// Invalid game state for changing the shoe.
+    #[error("invalid game state for changing the shoe")]
+    InvalidState,
+    /// The card is not in the shoe.
+    #[error("card not found in the shoe")]
+    CardNotFound,
+}
+
+/// Errors that can occur when replaying an action log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ReplayError {
+    /// The options the game is created with are invalid.
+    #[error(transparent)]
+    Options(#[from] OptionsError),
+    /// A logged action failed when replayed.
+    #[error("action {index} failed when replayed")]
+    ActionFailed {
+        /// Index of the action in the log.
+        index: usize,
+    },
+}
+
+/// Errors that can occur while playing an automated round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum RoundError {
+    /// Betting failed.
+    #[error(transparent)]
+    Bet(#[from] BetError),
+    /// Dealing failed.
+    #[error(transparent)]
+    Deal(#[from] DealError),
+    /// A player action failed.
+    #[error(transparent)]
+    Action(#[from] ActionError),
+    /// An insurance decision failed.
+    #[error(transparent)]
+    Insurance(#[from] InsuranceError),
+    /// Dealer play or showdown failed.
+    #[error(transparent)]
+    Showdown(#[from] ShowdownError),
+    /// Reshuffling between rounds failed.
+    #[error(transparent)]
+    Reshuffle(#[from] ReshuffleError),
+    /// There are no players seated at the table.
+    #[error("no players at the table")]
+    NoPlayers,
+    /// The tournament has already finished.
+    #[error("the tournament has already finished")]
+    TournamentOver,
+}
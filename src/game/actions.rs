@@ -1,9 +1,138 @@
 use crate::card::Card;
-use crate::error::ActionError;
+use crate::chips::Chips;
+use crate::error::{ActionError, RoundError};
 use crate::hand::{Hand, HandStatus};
-use crate::options::DoubleOption;
+use crate::options::{DoubleOption, GameOptions, SurrenderRule};
+use crate::payout::Payout;
+
+use super::{CardRecipient, Game, GameEvent, GameState, LoggedAction, PlayerDecision};
+
+/// Detailed result of a hit, including its effect on the turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HitOutcome {
+    /// The card drawn.
+    pub card: Card,
+    /// The hand's status after the card was added.
+    pub hand_status: HandStatus,
+    /// Whether the turn moved off the hand (it busted or reached 21).
+    pub turn_advanced: bool,
+    /// The `(player_id, hand_index)` now to act, or `None` once player turns
+    /// are over.
+    pub new_current: Option<(u8, usize)>,
+}
+
+/// Why a hand cannot be split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SplitDenyReason {
+    /// The hand is not two cards of the same value.
+    NotAPair,
+    /// The hand is two different ten-value cards, and the table only allows
+    /// splitting pairs of the same rank.
+    UnlikeTensDisallowed,
+    /// The player already has the maximum number of hands.
+    MaxSplitsReached,
+    /// The hand is from split aces, which cannot be split again.
+    AcesResplitDisallowed,
+}
+
+/// How a hand compares with the dealer's up card if it stood now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StandingHint {
+    /// The hand's total is higher than the dealer's visible total.
+    Ahead,
+    /// The hand's total equals the dealer's visible total.
+    Tied,
+    /// The hand's total is lower than the dealer's visible total, or the hand
+    /// has busted.
+    Behind,
+}
+
+/// A player's move, for routing every kind of player input through
+/// [`Game::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Action {
+    /// Draw a card.
+    Hit,
+    /// Keep the current hand.
+    Stand,
+    /// Double the bet and draw one card.
+    Double,
+    /// Split a pair into two hands.
+    Split,
+    /// Forfeit half the bet.
+    Surrender,
+    /// Take insurance.
+    TakeInsurance,
+    /// Decline insurance.
+    DeclineInsurance,
+    /// Switch the second cards of the player's two hands.
+    Switch,
+    /// Bet the given amount.
+    Bet(Chips),
+}
+
+impl From<PlayerDecision> for Action {
+    fn from(decision: PlayerDecision) -> Self {
+        match decision {
+            PlayerDecision::Hit => Self::Hit,
+            PlayerDecision::Stand => Self::Stand,
+            PlayerDecision::Double => Self::Double,
+            PlayerDecision::Split => Self::Split,
+            PlayerDecision::Surrender => Self::Surrender,
+        }
+    }
+}
 
-use super::{Game, GameState};
+/// The moves a player may make right now, as reported by
+/// [`Game::available_actions`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "each flag is an independent action"
+)]
+pub struct ActionSet {
+    /// Whether the hand may draw a card.
+    pub hit: bool,
+    /// Whether the hand may stand.
+    pub stand: bool,
+    /// Whether the hand may double down.
+    pub double: bool,
+    /// Whether the hand may be split.
+    pub split: bool,
+    /// Whether the hand may be surrendered.
+    pub surrender: bool,
+    /// Whether the player may take insurance.
+    pub insurance: bool,
+    /// Whether the player may switch the second cards of their two hands.
+    pub switch: bool,
+}
+
+/// Checks whether a hand's cards may be split under the table rules.
+fn pair_deny_reason(hand: &Hand, options: &GameOptions) -> Option<SplitDenyReason> {
+    let [first, second] = hand.cards() else {
+        return Some(SplitDenyReason::NotAPair);
+    };
+    if first.rank != second.rank {
+        let both_tens = first.rank >= 10 && second.rank >= 10;
+        if !both_tens {
+            return Some(SplitDenyReason::NotAPair);
+        }
+        if !options.split_any_ten {
+            return Some(SplitDenyReason::UnlikeTensDisallowed);
+        }
+    }
+    if first.rank == 1 && hand.is_from_split() && options.split_aces_only_once {
+        return Some(SplitDenyReason::AcesResplitDisallowed);
+    }
+    None
+}
 
 impl Game {
     fn ensure_player_turn(&self, player_id: u8, hand_index: usize) -> Result<(), ActionError> {
@@ -21,12 +150,59 @@ impl Game {
     fn advance_after_hand(&self) {
         self.advance_to_next_active_hand();
         if self.all_players_done() {
-            *self.state.lock() = GameState::DealerTurn;
+            self.set_state(&mut self.state.lock(), GameState::DealerTurn);
+        }
+    }
+
+    /// Applies a player's move.
+    ///
+    /// Hand actions act on the hand whose turn it is, so they only succeed
+    /// for the current player. Each action behaves exactly like the matching
+    /// method ([`hit`](Self::hit), [`stand`](Self::stand),
+    /// [`double_down`](Self::double_down), [`split`](Self::split),
+    /// [`surrender`](Self::surrender),
+    /// [`take_insurance`](Self::take_insurance),
+    /// [`decline_insurance`](Self::decline_insurance),
+    /// [`switch`](Self::switch) and [`bet`](Self::bet)),
+    /// and the values those methods return are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the matching method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Action, Chips, Game, GameOptions};
+    ///
+    /// let game = Game::new(GameOptions::default(), 42);
+    /// let player = game.join(Chips::new(100));
+    /// game.start_betting();
+    /// game.apply(player, Action::Bet(Chips::new(10))).unwrap();
+    /// assert_eq!(game.get_bet(player), Some(Chips::new(10)));
+    /// ```
+    pub fn apply(&self, player_id: u8, action: Action) -> Result<(), RoundError> {
+        let hand_index = self.current_turn().hand_index;
+        match action {
+            Action::Hit => self.hit(player_id, hand_index).map(|_| ())?,
+            Action::Stand => self.stand(player_id, hand_index)?,
+            Action::Double => self.double_down(player_id, hand_index).map(|_| ())?,
+            Action::Split => self.split(player_id, hand_index)?,
+            Action::Surrender => self.surrender(player_id, hand_index).map(|_| ())?,
+            Action::TakeInsurance => self.take_insurance(player_id).map(|_| ())?,
+            Action::DeclineInsurance => self.decline_insurance(player_id)?,
+            Action::Switch => self.switch(player_id)?,
+            Action::Bet(amount) => self.bet(player_id, amount)?,
         }
+        Ok(())
     }
 
     /// Player action: Hit (draw a card).
     ///
+    /// A hand that reaches `max_cards_per_hand` cards stands automatically,
+    /// and one that reaches the table's `charlie` card count without busting
+    /// becomes a [`HandStatus::Charlie`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the game is not in player turn state, it is not the
@@ -55,6 +231,7 @@ impl Game {
         // Draw a card
         drop(hands);
         let card = self.draw().ok_or(ActionError::NoCards)?;
+        self.emit_player_card(player_id, hand_index, card);
 
         // Add card to hand
         // SAFETY: player_id and hand_index were validated above via ok_or checks.
@@ -68,18 +245,66 @@ impl Game {
             .get_mut(hand_index)
             .expect("hand_index was validated above and cannot be removed mid-turn");
         hand.add_card(card);
+        self.check_charlie(hand);
+
+        // A hand at the card limit stands whatever its total
+        if hand.status() == HandStatus::Active
+            && hand.len() >= usize::from(self.options().max_cards_per_hand)
+        {
+            hand.set_status(HandStatus::Stand);
+        }
 
         let status = hand.status();
         drop(hands);
 
-        // If bust or 21, advance to next hand
+        // If bust or at the card limit, advance to next hand
         if status != HandStatus::Active {
             self.advance_after_hand();
         }
 
+        self.record(LoggedAction::Hit {
+            player_id,
+            hand_index,
+        });
         Ok(card)
     }
 
+    /// Player action: Hit, reporting the resulting hand status and turn.
+    ///
+    /// Behaves exactly like [`hit`](Self::hit), but also returns whether the
+    /// turn moved on and who acts next.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`hit`](Self::hit).
+    pub fn hit_detailed(
+        &self,
+        player_id: u8,
+        hand_index: usize,
+    ) -> Result<HitOutcome, ActionError> {
+        let card = self.hit(player_id, hand_index)?;
+
+        let hand_status = self
+            .hands
+            .lock()
+            .get(&player_id)
+            .and_then(|hands| hands.get(hand_index))
+            .map_or(HandStatus::Active, Hand::status);
+        let new_current = if *self.state.lock() == GameState::PlayerTurn {
+            self.current_player()
+                .map(|id| (id, self.current_turn().hand_index))
+        } else {
+            None
+        };
+
+        Ok(HitOutcome {
+            card,
+            hand_status,
+            turn_advanced: hand_status != HandStatus::Active,
+            new_current,
+        })
+    }
+
     /// Player action: Stand (keep current hand).
     ///
     /// # Errors
@@ -109,6 +334,10 @@ impl Game {
         // Advance to next hand
         self.advance_after_hand();
 
+        self.record(LoggedAction::Stand {
+            player_id,
+            hand_index,
+        });
         Ok(())
     }
 
@@ -137,39 +366,20 @@ impl Game {
             return Err(ActionError::HandNotActive);
         }
 
-        // Can only double on first two cards
-        if hand.len() != 2 {
-            return Err(ActionError::CannotDouble);
-        }
-
-        // Check if from split and double after split is allowed
-        if hand.is_from_split() && !self.options.double_after_split {
-            return Err(ActionError::CannotDouble);
-        }
-
-        // Check if value allows doubling
-        if !self.can_double_value(hand.value()) {
+        if !self.double_eligible(hand) {
             return Err(ActionError::CannotDouble);
         }
 
         let bet = hand.bet();
         drop(hands);
 
-        // Check if player has enough money
-        let mut money = self.money.lock();
-        let player_money = money
-            .get_mut(&player_id)
-            .ok_or(ActionError::PlayerNotFound)?;
-
-        if *player_money < bet {
-            return Err(ActionError::InsufficientFunds);
-        }
-
-        *player_money -= bet;
-        drop(money);
-
-        // Draw a card
+        // Draw the card first so running out of cards leaves the stake alone
         let card = self.draw().ok_or(ActionError::NoCards)?;
+        if let Err(err) = self.take_stake(player_id, bet) {
+            self.undraw(card);
+            return Err(err);
+        }
+        self.emit_player_card(player_id, hand_index, card);
 
         // Add card and double bet
         // SAFETY: player_id and hand_index were validated above via ok_or checks.
@@ -182,8 +392,10 @@ impl Game {
         let hand = player_hands
             .get_mut(hand_index)
             .expect("hand_index was validated above and cannot be removed mid-turn");
-        hand.double_bet();
+        let doubled = hand.double_bet();
+        debug_assert!(doubled, "hand was checked not to be doubled above");
         hand.add_card(card);
+        self.check_charlie(hand);
 
         // If not bust, set to stand
         if hand.status() == HandStatus::Active {
@@ -194,6 +406,10 @@ impl Game {
         // Advance to next hand
         self.advance_after_hand();
 
+        self.record(LoggedAction::DoubleDown {
+            player_id,
+            hand_index,
+        });
         Ok(card)
     }
 
@@ -212,6 +428,8 @@ impl Game {
     pub fn split(&self, player_id: u8, hand_index: usize) -> Result<(), ActionError> {
         self.ensure_player_turn(player_id, hand_index)?;
 
+        let options = self.options();
+
         // Get the hand
         let mut hands = self.hands.lock();
         let player_hands = hands
@@ -219,7 +437,7 @@ impl Game {
             .ok_or(ActionError::PlayerNotFound)?;
 
         // Check max splits
-        if player_hands.len() > self.options.split as usize {
+        if player_hands.len() > options.split as usize {
             return Err(ActionError::MaxSplitsReached);
         }
 
@@ -231,36 +449,32 @@ impl Game {
             return Err(ActionError::HandNotActive);
         }
 
-        // Check if can split
-        if !hand.can_split() {
+        // Check the pair and ace split restrictions
+        if pair_deny_reason(hand, &options).is_some() {
             return Err(ActionError::CannotSplit);
         }
 
-        // Check ace split restrictions
         let is_ace = hand.cards().first().is_some_and(|c| c.rank == 1);
-        if is_ace && hand.is_from_split() && self.options.split_aces_only_once {
-            return Err(ActionError::CannotSplit);
-        }
 
         let bet = hand.bet();
         drop(hands);
 
-        // Check if player has enough money
-        let mut money = self.money.lock();
-        let player_money = money
-            .get_mut(&player_id)
-            .ok_or(ActionError::PlayerNotFound)?;
-
-        if *player_money < bet {
-            return Err(ActionError::InsufficientFunds);
+        // Draw both cards before touching the hand or the stake, so a failed
+        // split leaves everything as it was
+        let card1 = self.draw().ok_or(ActionError::NoCards)?;
+        let Some(card2) = self.draw() else {
+            self.undraw(card1);
+            return Err(ActionError::NoCards);
+        };
+        if let Err(err) = self.take_stake(player_id, bet) {
+            self.undraw(card2);
+            self.undraw(card1);
+            return Err(err);
         }
 
-        *player_money -= bet;
-        drop(money);
-
         // Perform the split
         // SAFETY: player_id and hand_index were validated above via ok_or checks.
-        // can_split() was also verified, so take_split_card() will succeed.
+        // The pair was also verified, so split() will succeed.
         let mut hands = self.hands.lock();
         let player_hands = hands
             .get_mut(&player_id)
@@ -269,18 +483,14 @@ impl Game {
             .get_mut(hand_index)
             .expect("hand_index was validated above and cannot be removed mid-turn");
 
-        let split_card = hand
-            .take_split_card()
-            .expect("can_split() was verified above");
-        let new_hand = Hand::from_split(split_card, bet);
+        let new_hand = hand.split().expect("the pair was verified above");
 
-        // Draw a card for each hand
         drop(hands);
-        let card1 = self.draw().ok_or(ActionError::NoCards)?;
-        let card2 = self.draw().ok_or(ActionError::NoCards)?;
+        self.emit_player_card(player_id, hand_index, card1);
+        self.emit_player_card(player_id, hand_index + 1, card2);
 
         // SAFETY: player_id and hand_index were validated above.
-        // The lock was temporarily dropped to call draw(), but no other code path
+        // The lock was temporarily dropped to emit the card events, but no other code path
         // removes players or hands during a player's turn.
         let mut hands = self.hands.lock();
         let player_hands = hands
@@ -294,8 +504,7 @@ impl Game {
         hand.add_card(card1);
 
         // If split aces receive only one card, stand immediately
-        if is_ace && self.options.split_aces_receive_one_card && hand.status() == HandStatus::Active
-        {
+        if is_ace && options.split_aces_receive_one_card && hand.status() == HandStatus::Active {
             hand.set_status(HandStatus::Stand);
         }
 
@@ -303,9 +512,7 @@ impl Game {
         let mut new_hand = new_hand;
         new_hand.add_card(card2);
 
-        if is_ace
-            && self.options.split_aces_receive_one_card
-            && new_hand.status() == HandStatus::Active
+        if is_ace && options.split_aces_receive_one_card && new_hand.status() == HandStatus::Active
         {
             new_hand.set_status(HandStatus::Stand);
         }
@@ -314,32 +521,87 @@ impl Game {
         drop(hands);
 
         // If aces that auto-stand, advance
-        if is_ace && self.options.split_aces_receive_one_card {
+        if is_ace && options.split_aces_receive_one_card {
             self.advance_after_hand();
         }
 
+        self.record(LoggedAction::Split {
+            player_id,
+            hand_index,
+        });
         Ok(())
     }
 
+    /// Returns why the hand cannot be split, or `None` if its cards may be
+    /// split under the table's rules.
+    ///
+    /// Only the cards, the split limit and the ace resplit rule are
+    /// considered; turn order and funds are checked when splitting. Returns
+    /// `None` if the player or hand cannot be found.
+    pub fn split_deny_reason(&self, player_id: u8, hand_index: usize) -> Option<SplitDenyReason> {
+        let options = self.options();
+        let hands = self.hands.lock();
+        let player_hands = hands.get(&player_id)?;
+        let hand = player_hands.get(hand_index)?;
+        let reason = pair_deny_reason(hand, &options).or_else(|| {
+            (player_hands.len() > options.split as usize)
+                .then_some(SplitDenyReason::MaxSplitsReached)
+        });
+        drop(hands);
+        reason
+    }
+
+    /// Compares the hand's current total with the dealer's visible total.
+    ///
+    /// This is a rough hint for display: only the dealer's up card is
+    /// counted (unless the hole card is already revealed), so it says nothing
+    /// about the hole card or the cards the dealer will draw.
+    ///
+    /// Returns `None` if the player or hand cannot be found.
+    pub fn standing_outcome_vs_upcard(
+        &self,
+        player_id: u8,
+        hand_index: usize,
+    ) -> Option<StandingHint> {
+        let dealer_value = self.dealer_hand.lock().visible_value();
+        let hands = self.hands.lock();
+        let ordering = hands.get(&player_id)?.get(hand_index)?.beats(dealer_value);
+        drop(hands);
+
+        Some(match ordering {
+            core::cmp::Ordering::Greater => StandingHint::Ahead,
+            core::cmp::Ordering::Equal => StandingHint::Tied,
+            core::cmp::Ordering::Less => StandingHint::Behind,
+        })
+    }
+
     /// Player action: Surrender (forfeit half the bet).
     ///
+    /// With [`SurrenderRule::Early`], players may also surrender during the
+    /// insurance phase, in any order, before the dealer's hand is checked for
+    /// blackjack. The player's insurance decision is then taken as made.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the game is not in player turn state, surrender is
-    /// disabled, it is not the player's turn, the player or hand cannot be
-    /// found, or the hand is not eligible to surrender.
-    pub fn surrender(&self, player_id: u8, hand_index: usize) -> Result<usize, ActionError> {
-        if *self.state.lock() != GameState::PlayerTurn {
+    /// Returns an error if the game is not in player turn state (or, with
+    /// early surrender, insurance state), surrender is disabled, it is not
+    /// the player's turn, the player or hand cannot be found, or the hand is
+    /// not eligible to surrender.
+    pub fn surrender(&self, player_id: u8, hand_index: usize) -> Result<Chips, ActionError> {
+        let state = *self.state.lock();
+        let rule = self.options().surrender;
+        let early = state == GameState::Insurance && rule == SurrenderRule::Early;
+        if state != GameState::PlayerTurn && !early {
             return Err(ActionError::InvalidState);
         }
 
         // Check if surrender is allowed
-        if !self.options.surrender {
+        if rule == SurrenderRule::None {
             return Err(ActionError::CannotSurrender);
         }
 
         // Check if it's this player's turn
-        if !self.is_player_turn(player_id, hand_index) {
+        if !early && !self.is_player_turn(player_id, hand_index) {
             return Err(ActionError::NotYourTurn);
         }
 
@@ -356,8 +618,7 @@ impl Game {
             return Err(ActionError::HandNotActive);
         }
 
-        // Can only surrender on first two cards and not from split
-        if hand.len() != 2 || hand.is_from_split() {
+        if !self.surrender_eligible(hand) {
             return Err(ActionError::CannotSurrender);
         }
 
@@ -366,23 +627,295 @@ impl Game {
         drop(hands);
 
         // Return half the bet
-        #[expect(
-            clippy::cast_precision_loss,
-            reason = "f64 has sufficient precision for monetary values"
-        )]
-        let refund = self.round_payout((bet as f64) * 0.5, self.options.rounding_surrender);
+        let refund = Payout::HALF.winnings(bet, self.options().rounding_surrender);
         let mut money = self.money.lock();
         if let Some(player_money) = money.get_mut(&player_id) {
-            *player_money += refund;
+            *player_money = player_money.saturating_add(refund);
         }
         drop(money);
 
-        // Advance to next hand
-        self.advance_after_hand();
+        if early {
+            // Surrendering settles the insurance decision too
+            let mut decided = self.insurance_decided.lock();
+            if !decided.contains(&player_id) {
+                decided.push(player_id);
+            }
+            drop(decided);
+        } else {
+            // Advance to next hand
+            self.advance_after_hand();
+        }
 
+        self.record(LoggedAction::Surrender {
+            player_id,
+            hand_index,
+        });
         Ok(refund)
     }
 
+    /// Player action: Switch (swap the second cards of the player's two
+    /// hands), under `blackjack_switch`.
+    ///
+    /// The switch must come before either hand acts: both hands must still
+    /// hold their opening two cards, and it must be the player's turn. A
+    /// two-card 21 made by switching is not a natural. The turn returns to
+    /// the player's first hand that is still active.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in player turn state, it is not the
+    /// player's turn, the player cannot be found, or the table does not play
+    /// Blackjack Switch or the hands can no longer be switched.
+    pub fn switch(&self, player_id: u8) -> Result<(), ActionError> {
+        if *self.state.lock() != GameState::PlayerTurn {
+            return Err(ActionError::InvalidState);
+        }
+
+        if !self.options().blackjack_switch {
+            return Err(ActionError::CannotSwitch);
+        }
+
+        if self.current_player() != Some(player_id) {
+            return Err(ActionError::NotYourTurn);
+        }
+
+        let mut hands = self.hands.lock();
+        let player_hands = hands
+            .get_mut(&player_id)
+            .ok_or(ActionError::PlayerNotFound)?;
+        if !self.switch_eligible(player_id, player_hands) {
+            return Err(ActionError::CannotSwitch);
+        }
+        let [first, second] = player_hands.as_mut_slice() else {
+            return Err(ActionError::CannotSwitch);
+        };
+        if !first.switch_with(second) {
+            return Err(ActionError::CannotSwitch);
+        }
+        drop(hands);
+
+        self.current_turn.lock().hand_index = 0;
+        self.advance_if_current_inactive();
+        if self.all_players_done() {
+            self.set_state(&mut self.state.lock(), GameState::DealerTurn);
+        }
+
+        self.record(LoggedAction::Switch { player_id });
+        Ok(())
+    }
+
+    /// Returns whether the player may surrender the given hand right now.
+    ///
+    /// This is `true` only during the player's turn on that hand (or, with
+    /// [`SurrenderRule::Early`], during the insurance phase), with surrender
+    /// offered at the table, and while the hand still holds just its opening
+    /// two cards (so never after a hit or on a split hand).
+    pub fn surrender_allowed_now(&self, player_id: u8, hand_index: usize) -> bool {
+        let allowed = match self.state() {
+            GameState::PlayerTurn => self.is_player_turn(player_id, hand_index),
+            GameState::Insurance => self.options().surrender == SurrenderRule::Early,
+            _ => false,
+        };
+        if !allowed {
+            return false;
+        }
+
+        self.hands
+            .lock()
+            .get(&player_id)
+            .and_then(|hands| hands.get(hand_index))
+            .is_some_and(|hand| {
+                hand.status() == HandStatus::Active && self.surrender_eligible(hand)
+            })
+    }
+
+    /// Returns whether hitting the given hand is allowed and worthwhile right
+    /// now.
+    ///
+    /// This is `true` only during the player's turn on that hand, while the
+    /// hand is active and below 21. A hand at 21 may still legally be hit,
+    /// but no card can improve it, so it is reported as `false`.
+    pub fn can_hit(&self, player_id: u8, hand_index: usize) -> bool {
+        if *self.state.lock() != GameState::PlayerTurn
+            || !self.is_player_turn(player_id, hand_index)
+        {
+            return false;
+        }
+
+        self.hands
+            .lock()
+            .get(&player_id)
+            .and_then(|hands| hands.get(hand_index))
+            .is_some_and(|hand| hand.status() == HandStatus::Active && hand.value() < 21)
+    }
+
+    /// Returns which moves the player may make on the given hand right now.
+    ///
+    /// During player turns this applies the same checks as [`hit`](Self::hit),
+    /// [`stand`](Self::stand), [`double_down`](Self::double_down),
+    /// [`split`](Self::split), [`surrender`](Self::surrender) and
+    /// [`switch`](Self::switch): turn order, hand status, table options, split
+    /// limits and the player's funds. As
+    /// with [`can_hit`](Self::can_hit), hitting a hand at 21 is not offered.
+    /// During the insurance phase only `insurance` and, with
+    /// [`SurrenderRule::Early`], `surrender` can be set. In every other state
+    /// nothing is allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, Game, GameOptions};
+    ///
+    /// let game = Game::new(GameOptions::default(), 42);
+    /// let player = game.join(Chips::new(100));
+    /// assert!(!game.available_actions(player, 0).stand);
+    /// ```
+    pub fn available_actions(&self, player_id: u8, hand_index: usize) -> ActionSet {
+        let state = *self.state.lock();
+        if state == GameState::Insurance {
+            return ActionSet {
+                insurance: self.is_insurance_offered()
+                    && !self.insurance_decided.lock().contains(&player_id)
+                    && self.can_afford_insurance(player_id),
+                surrender: self.surrender_allowed_now(player_id, hand_index),
+                ..ActionSet::default()
+            };
+        }
+
+        if state != GameState::PlayerTurn || !self.is_player_turn(player_id, hand_index) {
+            return ActionSet::default();
+        }
+
+        let options = self.options();
+        let money = self
+            .money
+            .lock()
+            .get(&player_id)
+            .copied()
+            .unwrap_or_default();
+        let hands = self.hands.lock();
+        let Some(player_hands) = hands.get(&player_id) else {
+            return ActionSet::default();
+        };
+        let Some(hand) = player_hands.get(hand_index) else {
+            return ActionSet::default();
+        };
+        if hand.status() != HandStatus::Active {
+            return ActionSet::default();
+        }
+
+        let has_funds = money >= hand.bet();
+        let actions = ActionSet {
+            hit: hand.value() < 21,
+            stand: true,
+            double: self.double_eligible(hand) && has_funds,
+            split: player_hands.len() <= options.split as usize
+                && pair_deny_reason(hand, &options).is_none()
+                && has_funds,
+            surrender: self.surrender_eligible(hand),
+            insurance: false,
+            switch: self.options().blackjack_switch
+                && self.switch_eligible(player_id, player_hands),
+        };
+        drop(hands);
+        actions
+    }
+
+    /// Returns how many hands are still waiting to act, including the current one.
+    ///
+    /// Counts `Active` hands from the current turn position onward. Returns 0
+    /// outside of player turns.
+    pub fn active_hands_remaining(&self) -> usize {
+        if *self.state.lock() != GameState::PlayerTurn {
+            return 0;
+        }
+
+        let turn = self.current_turn();
+        let order = self.betting_order.lock();
+        let hands = self.hands.lock();
+
+        order
+            .iter()
+            .enumerate()
+            .skip(turn.player_index)
+            .filter_map(|(player_index, player_id)| {
+                let first = if player_index == turn.player_index {
+                    turn.hand_index
+                } else {
+                    0
+                };
+                hands.get(player_id).map(|player_hands| {
+                    player_hands
+                        .iter()
+                        .skip(first)
+                        .filter(|hand| hand.status() == HandStatus::Active)
+                        .count()
+                })
+            })
+            .sum()
+    }
+
+    /// Checks the table rule and card count for surrendering a hand.
+    ///
+    /// Surrender is only possible on the opening two cards of a hand that was
+    /// not formed by a split.
+    fn surrender_eligible(&self, hand: &Hand) -> bool {
+        self.options().surrender != SurrenderRule::None && hand.len() == 2 && !hand.is_from_split()
+    }
+
+    /// Checks whether a player's hands can still be switched.
+    ///
+    /// The player must hold exactly two untouched hands: two cards each, not
+    /// switched, doubled or surrendered, and not paid out during the deal.
+    fn switch_eligible(&self, player_id: u8, player_hands: &[Hand]) -> bool {
+        let prepaid = self.prepaid_hands.lock();
+        player_hands.len() == 2
+            && player_hands.iter().enumerate().all(|(hand_index, hand)| {
+                hand.len() == 2
+                    && !hand.is_switched()
+                    && !hand.is_doubled()
+                    && matches!(hand.status(), HandStatus::Active | HandStatus::Blackjack)
+                    && !prepaid.contains(&(player_id, hand_index))
+            })
+    }
+
+    /// Checks the table rules and card count for doubling down on a hand.
+    ///
+    /// A hand may double once, on its first two cards, if its value is
+    /// allowed and, for split hands, doubling after a split is allowed.
+    /// Takes the extra stake for a double or split from the player's money.
+    fn take_stake(&self, player_id: u8, amount: Chips) -> Result<(), ActionError> {
+        let mut money = self.money.lock();
+        let player_money = money
+            .get_mut(&player_id)
+            .ok_or(ActionError::PlayerNotFound)?;
+        *player_money = player_money
+            .checked_sub(amount)
+            .ok_or(ActionError::InsufficientFunds)?;
+        drop(money);
+        Ok(())
+    }
+
+    fn double_eligible(&self, hand: &Hand) -> bool {
+        hand.len() == 2
+            && !hand.is_doubled()
+            && (!hand.is_from_split() || self.options().double_after_split)
+            && self.can_double_value(hand.value())
+    }
+
+    /// Marks an active hand as a Charlie once it reaches the table's
+    /// Charlie card count.
+    fn check_charlie(&self, hand: &mut Hand) {
+        if hand.status() == HandStatus::Active
+            && self
+                .options()
+                .charlie
+                .is_some_and(|cards| hand.len() >= usize::from(cards))
+        {
+            hand.set_status(HandStatus::Charlie);
+        }
+    }
+
     /// Checks if it's the specified player's turn on the specified hand.
     fn is_player_turn(&self, player_id: u8, hand_index: usize) -> bool {
         let turn = self.current_turn.lock();
@@ -395,8 +928,30 @@ impl Game {
         }
     }
 
+    /// Announces a card drawn to a player's hand.
+    fn emit_player_card(&self, player_id: u8, hand_index: usize, card: Card) {
+        self.emit(GameEvent::CardDealt {
+            recipient: CardRecipient::Player {
+                player_id,
+                hand_index,
+            },
+            card,
+            face_up: true,
+        });
+    }
+
     /// Advances to the next active hand (skipping blackjacks, busts, stands).
     pub(super) fn advance_to_next_active_hand(&self) {
+        let before = self.current_turn();
+        self.move_to_next_active_hand();
+        let turn = self.current_turn();
+        if turn != before && turn.player_index < self.betting_order.lock().len() {
+            self.emit(GameEvent::TurnAdvanced { turn });
+        }
+    }
+
+    /// Moves the turn position to the next active hand.
+    fn move_to_next_active_hand(&self) {
         let mut turn = self.current_turn.lock();
         let order = self.betting_order.lock();
         let hands = self.hands.lock();
@@ -436,7 +991,7 @@ impl Game {
     }
 
     /// Checks if all players have finished their turns.
-    fn all_players_done(&self) -> bool {
+    pub(super) fn all_players_done(&self) -> bool {
         let turn = self.current_turn.lock();
         let order = self.betting_order.lock();
         turn.player_index >= order.len()
@@ -444,7 +999,7 @@ impl Game {
 
     /// Checks if double down is allowed for the given hand value.
     fn can_double_value(&self, value: u8) -> bool {
-        match self.options.double {
+        match self.options().double {
             DoubleOption::Any => true,
             DoubleOption::NineOrTen => value == 9 || value == 10,
             DoubleOption::NineThrough11 => (9..=11).contains(&value),
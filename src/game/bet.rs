@@ -1,34 +1,163 @@
+use core::sync::atomic::Ordering;
+
 use alloc::vec::Vec;
 
+use crate::card::Card;
+use crate::chips::Chips;
 use crate::error::{BetError, DealError};
-use crate::hand::Hand;
+use crate::hand::{DealerHand, Hand};
+use crate::options::{DealOrder, GameOptions, SurrenderRule};
+use crate::result::SideBetResult;
+use crate::side_bet::SideBetKind;
 
-use super::{Game, GameState, TurnPosition};
+use super::{CardRecipient, Game, GameEvent, GameState, LoggedAction, TurnPosition};
 
 impl Game {
     fn deal_one_card_to_players(&self, players: &[u8]) {
         for &player_id in players {
-            if let Some(card) = self.draw() {
+            let hand_count = self.hands.lock().get(&player_id).map_or(0, Vec::len);
+            for hand_index in 0..hand_count {
+                let Some(card) = self.draw() else {
+                    continue;
+                };
                 let mut hands = self.hands.lock();
-                if let Some(player_hands) = hands.get_mut(&player_id) {
-                    if let Some(hand) = player_hands.first_mut() {
-                        hand.add_card(card);
-                    }
+                if let Some(hand) = hands
+                    .get_mut(&player_id)
+                    .and_then(|player_hands| player_hands.get_mut(hand_index))
+                {
+                    hand.add_card(card);
                 }
+                drop(hands);
+                self.emit(GameEvent::CardDealt {
+                    recipient: CardRecipient::Player {
+                        player_id,
+                        hand_index,
+                    },
+                    card,
+                    face_up: true,
+                });
             }
         }
     }
 
+    fn deal_one_card_to_dealer(&self) {
+        if let Some(card) = self.draw() {
+            let mut dealer = self.dealer_hand.lock();
+            dealer.add_card(card);
+            // Only the second card is the hole card
+            let face_up = dealer.len() != 2 || dealer.is_hole_revealed();
+            drop(dealer);
+            self.emit(GameEvent::CardDealt {
+                recipient: CardRecipient::Dealer,
+                card,
+                face_up,
+            });
+        }
+    }
+
+    /// Pays every player natural in full and records the payout.
+    fn pay_naturals_now(&self, players: &[u8]) {
+        for &player_id in players {
+            let naturals: Vec<(usize, Chips)> = self
+                .hands
+                .lock()
+                .get(&player_id)
+                .map(|hands| {
+                    hands
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, hand)| hand.is_blackjack())
+                        .map(|(hand_index, hand)| (hand_index, hand.bet()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            if naturals.is_empty() {
+                continue;
+            }
+
+            let mut payout = Chips::ZERO;
+            for (hand_index, bet) in naturals {
+                payout = payout.saturating_add(self.blackjack_payout(bet));
+                self.prepaid_hands.lock().push((player_id, hand_index));
+            }
+            if let Some(money) = self.money.lock().get_mut(&player_id) {
+                *money = money.saturating_add(payout);
+            }
+            self.immediate_payouts.lock().insert(player_id, payout);
+        }
+    }
+
+    /// Settles the side bets decided by the opening deal.
+    ///
+    /// The winnings are paid at showdown. Players with side bets that wait
+    /// for the dealer's final hand keep their opening cards for the showdown.
+    fn settle_side_bets(&self, players: &[u8], options: &GameOptions) {
+        let side_bets = self.side_bets.lock().clone();
+        let dealer = self.dealer_hand.lock().clone();
+        let hands = self.hands.lock();
+        let mut results = self.side_bet_results.lock();
+        let mut opening_cards = self.side_bet_cards.lock();
+        results.clear();
+        opening_cards.clear();
+
+        for &player_id in players {
+            let (Some(placed), Some(hand)) = (
+                side_bets.get(&player_id),
+                hands.get(&player_id).and_then(|hands| hands.first()),
+            ) else {
+                continue;
+            };
+
+            let mut settled = Vec::new();
+            for &(kind, bet) in placed {
+                if options.side_bet(kind).settles_at_showdown() {
+                    opening_cards.insert(player_id, hand.cards().to_vec());
+                } else {
+                    settled.push(self.side_bet_result(kind, bet, hand.cards(), &dealer, options));
+                }
+            }
+            results.insert(player_id, settled);
+        }
+        drop(opening_cards);
+        drop(results);
+        drop(hands);
+    }
+
+    /// Settles one side bet against the given cards.
+    pub(super) fn side_bet_result(
+        &self,
+        kind: SideBetKind,
+        bet: Chips,
+        player_cards: &[Card],
+        dealer: &DealerHand,
+        options: &GameOptions,
+    ) -> SideBetResult {
+        let payout = options
+            .side_bet(kind)
+            .payout_ratio(player_cards, dealer)
+            .map_or(Chips::ZERO, |ratio| {
+                bet.saturating_add(ratio.winnings(bet, options.rounding_default))
+            });
+        SideBetResult { kind, bet, payout }
+    }
+
     /// Places a bet for the specified player.
     ///
+    /// A player may bet their whole bankroll and play the round with no money
+    /// left: hitting, standing and surrendering still work, while doubling,
+    /// splitting and insurance fail for lack of funds. Winnings are credited
+    /// at showdown as usual.
+    ///
+    /// Under `blackjack_switch` the amount is placed on each of the player's
+    /// two hands, so twice the amount is taken.
+    ///
     /// # Errors
     ///
     /// Returns an error if the game is not in betting state, the player cannot
-    /// be found, the bet is zero, or the player lacks funds.
-    pub fn bet(&self, player_id: u8, amount: usize) -> Result<(), BetError> {
-        if amount == 0 {
-            return Err(BetError::ZeroBet);
-        }
+    /// be found, the bet is zero, outside the table's `min_bet` and `max_bet`
+    /// or not a multiple of its `bet_increment`, or the player lacks funds.
+    pub fn bet(&self, player_id: u8, amount: Chips) -> Result<(), BetError> {
+        let total = Self::check_bet(&self.options(), amount)?;
 
         let state = self.state.lock();
         if *state != GameState::Betting {
@@ -36,30 +165,182 @@ impl Game {
         }
         drop(state);
 
+        let mut money = self.money.lock();
+        let player_money = money.get_mut(&player_id).ok_or(BetError::PlayerNotFound)?;
+        *player_money = player_money
+            .checked_sub(total)
+            .ok_or(BetError::InsufficientFunds)?;
+        drop(money);
+
+        self.bets.lock().insert(player_id, amount);
+
+        self.record(LoggedAction::Bet { player_id, amount });
+        Ok(())
+    }
+
+    /// Checks a main bet against the table limits, returning the total taken
+    /// from the player (the amount on each of their hands).
+    pub(crate) fn check_bet(options: &GameOptions, amount: Chips) -> Result<Chips, BetError> {
+        if amount.is_zero() {
+            return Err(BetError::ZeroBet);
+        }
+        if amount < options.min_bet {
+            return Err(BetError::BelowMinimum);
+        }
+        if amount > options.max_bet {
+            return Err(BetError::AboveMaximum);
+        }
+        if amount
+            .checked_rem(options.bet_increment)
+            .is_some_and(|rest| !rest.is_zero())
+        {
+            return Err(BetError::InvalidIncrement);
+        }
+
+        amount
+            .checked_mul(Self::hands_per_player(options) as u64)
+            .ok_or(BetError::InsufficientFunds)
+    }
+
+    /// Places a side bet for the specified player.
+    ///
+    /// The player must have placed their main bet first, and may place each
+    /// kind of side bet once per round. The amount is taken now; the side bet
+    /// is settled from the opening deal and paid at showdown, where it is
+    /// reported in [`PlayerResult::side_bets`](crate::PlayerResult::side_bets).
+    /// Side bets are not kept by
+    /// [`clear_round_keep_bets`](Self::clear_round_keep_bets).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in betting state, the player cannot
+    /// be found, the amount is zero, the player has no main bet or already
+    /// placed this side bet, or the player lacks funds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, Game, GameOptions, SideBetKind};
+    ///
+    /// let game = Game::new(GameOptions::default(), 42);
+    /// let player = game.join(Chips::new(100));
+    /// game.start_betting();
+    /// game.bet(player, Chips::new(10)).unwrap();
+    /// game.place_side_bet(player, SideBetKind::PerfectPairs, Chips::new(5))
+    ///     .unwrap();
+    /// assert_eq!(game.get_money(player), Some(Chips::new(85)));
+    /// ```
+    pub fn place_side_bet(
+        &self,
+        player_id: u8,
+        kind: SideBetKind,
+        amount: Chips,
+    ) -> Result<(), BetError> {
+        if amount.is_zero() {
+            return Err(BetError::ZeroBet);
+        }
+
+        if *self.state.lock() != GameState::Betting {
+            return Err(BetError::InvalidState);
+        }
+
         let mut money = self.money.lock();
         let player_money = money.get_mut(&player_id).ok_or(BetError::PlayerNotFound)?;
 
-        if *player_money < amount {
-            return Err(BetError::InsufficientFunds);
+        if !self.bets.lock().contains_key(&player_id) {
+            return Err(BetError::NoBet);
         }
 
-        *player_money -= amount;
-        drop(money);
+        let mut side_bets = self.side_bets.lock();
+        let placed = side_bets.entry(player_id).or_default();
+        if placed.iter().any(|&(placed_kind, _)| placed_kind == kind) {
+            return Err(BetError::SideBetAlreadyPlaced);
+        }
 
-        self.bets.lock().insert(player_id, amount);
+        *player_money = player_money
+            .checked_sub(amount)
+            .ok_or(BetError::InsufficientFunds)?;
+        placed.push((kind, amount));
+        drop(side_bets);
+        drop(money);
 
+        self.record(LoggedAction::PlaceSideBet {
+            player_id,
+            kind,
+            amount,
+        });
         Ok(())
     }
 
-    /// Deals initial cards to all players and the dealer.
+    /// Cancels the specified player's bet and refunds it.
+    ///
+    /// Returns the refunded amount, which covers both hands under
+    /// `blackjack_switch` and any side bets. The player is not dealt into the
+    /// round unless they bet again.
     ///
     /// # Errors
     ///
-    /// Returns an error if the game is not in betting state, no bets have been
-    /// placed, or there are not enough cards in the shoe.
-    pub fn deal(&self) -> Result<(), DealError> {
-        let mut state = self.state.lock();
-        if *state != GameState::Betting {
+    /// Returns an error if the game is not in betting state, the player cannot
+    /// be found, or the player has not bet.
+    pub fn cancel_bet(&self, player_id: u8) -> Result<Chips, BetError> {
+        if *self.state.lock() != GameState::Betting {
+            return Err(BetError::InvalidState);
+        }
+
+        let mut money = self.money.lock();
+        let player_money = money.get_mut(&player_id).ok_or(BetError::PlayerNotFound)?;
+
+        let amount = self
+            .bets
+            .lock()
+            .remove(&player_id)
+            .ok_or(BetError::NoBet)?
+            .saturating_mul(Self::hands_per_player(&self.options()) as u64)
+            .saturating_add(self.side_bets.lock().remove(&player_id).map_or(
+                Chips::ZERO,
+                |side_bets| {
+                    side_bets
+                        .iter()
+                        .map(|&(_, bet)| bet)
+                        .fold(Chips::ZERO, Chips::saturating_add)
+                },
+            ));
+        *player_money = player_money.saturating_add(amount);
+        drop(money);
+
+        self.record(LoggedAction::CancelBet { player_id });
+        Ok(amount)
+    }
+
+    /// Checks whether [`deal`](Self::deal) would succeed, without changing
+    /// anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `deal` would return.
+    pub fn can_deal(&self) -> Result<(), DealError> {
+        let state = *self.state.lock();
+        self.check_deal(state, &self.options()).map(|_| ())
+    }
+
+    /// Returns whether the dealer takes a hole card during the deal.
+    ///
+    /// The hole card comes with the second round of cards, so it is skipped
+    /// under `no_hole_card` or when players get fewer than two cards.
+    const fn deals_hole_card(options: &GameOptions) -> bool {
+        !options.no_hole_card && options.initial_cards >= 2
+    }
+
+    /// Returns how many hands each player is dealt: two under
+    /// `blackjack_switch`, otherwise one.
+    pub(crate) const fn hands_per_player(options: &GameOptions) -> usize {
+        if options.blackjack_switch { 2 } else { 1 }
+    }
+
+    /// Checks the preconditions for dealing and returns the players who bet,
+    /// in seat order.
+    fn check_deal(&self, state: GameState, options: &GameOptions) -> Result<Vec<u8>, DealError> {
+        if state != GameState::Betting {
             return Err(DealError::InvalidState);
         }
 
@@ -68,11 +349,8 @@ impl Game {
             return Err(DealError::NoBets);
         }
 
-        let player_count = bets.len();
-        let cards_needed = (player_count + 1) * 2;
-
-        if self.cards_remaining() < cards_needed {
-            return Err(DealError::NotEnoughCards);
+        if options.require_all_bet && self.players.lock().iter().any(|id| !bets.contains_key(id)) {
+            return Err(DealError::MissingBet);
         }
 
         // Get player IDs who have bet (in order)
@@ -82,9 +360,68 @@ impl Game {
             .filter(|id| bets.contains_key(*id))
             .copied()
             .collect();
-        drop(bets);
         drop(players);
 
+        // Every bet must belong to exactly one seat
+        let unique = betting_players
+            .iter()
+            .enumerate()
+            .all(|(index, id)| !betting_players[..index].contains(id));
+        if !unique || betting_players.len() != bets.len() {
+            return Err(DealError::InconsistentState);
+        }
+        drop(bets);
+
+        let hand_count = betting_players.len() * Self::hands_per_player(options);
+        let initial_cards = usize::from(options.initial_cards);
+        let dealer_cards = if Self::deals_hole_card(options) { 2 } else { 1 };
+        let cards_needed = hand_count * initial_cards + dealer_cards + options.reserve_cards;
+
+        if self.cards_remaining() < cards_needed {
+            return Err(DealError::NotEnoughCards);
+        }
+
+        Ok(betting_players)
+    }
+
+    /// Deals initial cards to all players and the dealer.
+    ///
+    /// With `auto_resolve_insurance`, an ace up card does not enter the
+    /// insurance state: every player declines and the round either ends on a
+    /// dealer blackjack or moves straight to player turns. When no hand is
+    /// left to play after the deal (e.g. every hand is a natural), the game
+    /// moves on to the dealer's turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in betting state, no bets have been
+    /// placed, a seated player has not bet while `require_all_bet` is set, a
+    /// player is seated twice or a bet belongs to no seated player, or there
+    /// are not enough cards in the shoe for the initial deal plus
+    /// `reserve_cards`.
+    pub fn deal(&self) -> Result<(), DealError> {
+        self.deal_round()?;
+        self.record(LoggedAction::Deal);
+        Ok(())
+    }
+
+    /// Deals the round without recording the call.
+    ///
+    /// The low-shoe callback is held back until the deal has released its
+    /// locks, so it may call back into the game.
+    fn deal_round(&self) -> Result<(), DealError> {
+        self.dealing.store(true, Ordering::SeqCst);
+        let dealt = self.deal_round_locked();
+        self.dealing.store(false, Ordering::SeqCst);
+        self.fire_pending_low_shoe();
+        dealt
+    }
+
+    fn deal_round_locked(&self) -> Result<(), DealError> {
+        let mut state = self.state.lock();
+        let options = self.options();
+        let betting_players = self.check_deal(*state, &options)?;
+
         // Store betting order
         (*self.betting_order.lock()).clone_from(&betting_players);
 
@@ -93,28 +430,51 @@ impl Game {
         hands.clear();
 
         for &player_id in &betting_players {
-            let bet = self.bets.lock().get(&player_id).copied().unwrap_or(0);
-            hands.insert(player_id, alloc::vec![Hand::new(bet)]);
+            let bet = self
+                .bets
+                .lock()
+                .get(&player_id)
+                .copied()
+                .unwrap_or_default();
+            let hand = Hand::new(bet).with_blackjack_qualifier(options.blackjack_on);
+            hands.insert(
+                player_id,
+                alloc::vec![hand; Self::hands_per_player(&options)],
+            );
         }
         drop(hands);
 
-        // Clear dealer's hand
-        self.dealer_hand.lock().clear();
+        // Clear dealer's hand; with Double Exposure the hole card is dealt
+        // face up
+        let mut dealer = self.dealer_hand.lock();
+        dealer.clear();
+        if options.double_exposure {
+            dealer.reveal_hole();
+        }
+        drop(dealer);
+        self.cards_dealt.store(0, Ordering::SeqCst);
 
-        // Deal first card to each player
-        self.deal_one_card_to_players(&betting_players);
+        match options.deal_order {
+            DealOrder::Standard => {
+                // Deal first card to each player
+                self.deal_one_card_to_players(&betting_players);
 
-        // Dealer's first card (up card)
-        if let Some(card) = self.draw() {
-            self.dealer_hand.lock().add_card(card);
+                // Dealer's first card (up card)
+                self.deal_one_card_to_dealer();
+            }
+            DealOrder::DealerUpcardFirst => {
+                self.deal_one_card_to_dealer();
+                self.deal_one_card_to_players(&betting_players);
+            }
         }
 
-        // Second card to each player
-        self.deal_one_card_to_players(&betting_players);
-
-        // Dealer's second card (hole card)
-        if let Some(card) = self.draw() {
-            self.dealer_hand.lock().add_card(card);
+        // Remaining cards to each player, with the dealer's hole card after
+        // the second round
+        for round in 1..options.initial_cards {
+            self.deal_one_card_to_players(&betting_players);
+            if round == 1 && Self::deals_hole_card(&options) {
+                self.deal_one_card_to_dealer();
+            }
         }
 
         // Initialize turn to first player, first hand
@@ -123,26 +483,92 @@ impl Game {
             hand_index: 0,
         };
 
-        // Clear insurance state
+        self.settle_side_bets(&betting_players, &options);
+
+        // Clear insurance and settlement state
         self.insurance_bets.lock().clear();
         self.insurance_decided.lock().clear();
+        self.settled_players.lock().clear();
 
         // Check if dealer's up card is an Ace and insurance is offered
-        let dealer_up_card_is_ace = self
-            .dealer_hand
-            .lock()
-            .up_card()
-            .is_some_and(|c| c.rank == 1);
+        let dealer_up_card_is_ace = self.dealer_hand.lock().has_ace_upcard();
+
+        // Pay naturals now if the dealer cannot have blackjack
+        self.immediate_payouts.lock().clear();
+        self.prepaid_hands.lock().clear();
+        self.even_money.lock().clear();
+        let dealer_can_have_blackjack =
+            dealer_up_card_is_ace || self.dealer_hand.lock().upcard_value() == 10;
+        if options.immediate_blackjack_payout && !dealer_can_have_blackjack {
+            self.pay_naturals_now(&betting_players);
+        }
 
-        if dealer_up_card_is_ace && self.options.insurance {
-            *state = GameState::Insurance;
+        let offers_insurance =
+            dealer_up_card_is_ace && options.insurance && !options.double_exposure;
+        // With early surrender, players get a chance to surrender before the
+        // dealer checks for blackjack
+        let early_surrender_window = options.surrender == SurrenderRule::Early
+            && !options.auto_resolve_insurance
+            && !options.double_exposure
+            && Self::deals_hole_card(&options)
+            && (offers_insurance || (options.dealer_peek && dealer_can_have_blackjack));
+
+        if offers_insurance || early_surrender_window {
+            if options.auto_resolve_insurance {
+                // Everyone declines and the dealer's hand is checked right away
+                self.insurance_decided.lock().clone_from(&betting_players);
+                drop(state);
+                self.resolve_insurance();
+            } else {
+                self.set_state(&mut state, GameState::Insurance);
+            }
+        } else if options.dealer_peek && self.dealer_hand.lock().is_blackjack() {
+            // The dealer peeked and has blackjack: the round ends now
+            self.reveal_dealer_hole();
+            self.set_state(&mut state, GameState::RoundOver);
+            drop(state);
         } else {
             // Skip players with blackjack
-            self.advance_if_current_inactive();
-            *state = GameState::PlayerTurn;
+            self.begin_player_turns(&mut state);
             drop(state);
         }
 
         Ok(())
     }
+
+    /// Deals the round from the given cards instead of the shoe.
+    ///
+    /// `cards` are drawn in order, first card first, for the initial deal and
+    /// every later draw this round (hits, doubles, splits, and the dealer). The
+    /// shoe itself is left untouched, so dealing the same list at several
+    /// tables produces identical rounds. The supplied cards are dropped by
+    /// [`clear_round`](Self::clear_round) without reaching the discard pile; if
+    /// they run out mid-round, draws fail rather than falling back to the shoe.
+    ///
+    /// Penetration and [`reshuffle`](Self::reshuffle) only concern the shoe:
+    /// cards dealt from the list do not count toward penetration, and the low
+    /// shoe callback is not fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`deal`](Self::deal), with the card count
+    /// checked against `cards`. On error the shoe is used again.
+    pub fn deal_from(&self, cards: &[Card]) -> Result<(), DealError> {
+        if *self.state.lock() != GameState::Betting {
+            return Err(DealError::InvalidState);
+        }
+
+        let mut round_shoe = cards.to_vec();
+        round_shoe.reverse();
+        *self.round_shoe.lock() = Some(round_shoe);
+
+        let dealt = self.deal_round();
+        match dealt {
+            Ok(()) => self.record(LoggedAction::DealFrom {
+                cards: cards.to_vec(),
+            }),
+            Err(_) => *self.round_shoe.lock() = None,
+        }
+        dealt
+    }
 }
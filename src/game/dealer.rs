@@ -1,30 +1,17 @@
 use alloc::vec::Vec;
 
+use crate::bonus::spanish_21_bonus;
 use crate::card::Card;
+use crate::chips::Chips;
+use crate::compare::{Tie, compare_blackjack};
 use crate::error::ShowdownError;
 use crate::hand::HandStatus;
-use crate::options::RoundingMode;
-use crate::result::{HandOutcome, HandResult, PlayerResult, RoundResult};
+use crate::payout::Payout;
+use crate::result::{
+    HandOutcome, HandResult, LedgerEntry, LedgerSource, PlayerResult, RoundResult,
+};
 
-use super::{Game, GameState};
-
-#[cfg(feature = "std")]
-fn round_amount(amount: f64, mode: RoundingMode) -> usize {
-    match mode {
-        RoundingMode::Up => amount.ceil() as usize,
-        RoundingMode::Down => amount.floor() as usize,
-        RoundingMode::Nearest => amount.round() as usize,
-    }
-}
-
-#[cfg(all(not(feature = "std"), feature = "alloc"))]
-fn round_amount(amount: f64, mode: RoundingMode) -> usize {
-    match mode {
-        RoundingMode::Up => libm::ceil(amount) as usize,
-        RoundingMode::Down => libm::floor(amount) as usize,
-        RoundingMode::Nearest => libm::round(amount) as usize,
-    }
-}
+use super::{CardRecipient, Game, GameEvent, GameState, LoggedAction};
 
 impl Game {
     /// Checks if any player has a non-busted, non-surrendered hand.
@@ -32,7 +19,9 @@ impl Game {
         for player_hands in self.hands.lock().values() {
             for hand in player_hands {
                 match hand.status() {
-                    HandStatus::Stand | HandStatus::Blackjack => return true,
+                    HandStatus::Stand | HandStatus::Blackjack | HandStatus::Charlie => {
+                        return true;
+                    }
                     _ => {}
                 }
             }
@@ -40,11 +29,23 @@ impl Game {
         false
     }
 
+    /// Returns whether the dealer's current hand must draw under this table's
+    /// soft 17 rule.
+    ///
+    /// This looks at the full hand, including an unrevealed hole card.
+    pub fn dealer_should_hit(&self) -> bool {
+        let stand_on_soft_17 = self.options().stand_on_soft_17;
+        self.dealer_hand.lock().should_hit(stand_on_soft_17)
+    }
+
     /// Dealer plays their hand according to the rules.
     ///
     /// The dealer reveals their hole card and draws until reaching 17 or higher.
     /// If `stand_on_soft_17` is true, dealer stands on soft 17.
-    /// Otherwise, dealer hits on soft 17.
+    /// Otherwise, dealer hits on soft 17. If every player hand has busted or
+    /// surrendered, the dealer does not draw. With `no_hole_card`, the
+    /// dealer's second card is drawn here (and included in the returned
+    /// cards), or not at all when there is nothing to play against.
     ///
     /// Returns the cards drawn by the dealer.
     ///
@@ -53,51 +54,77 @@ impl Game {
     /// Returns an error if the game is not in dealer turn state or the shoe is
     /// empty while the dealer must draw.
     pub fn dealer_play(&self) -> Result<Vec<Card>, ShowdownError> {
-        if *self.state.lock() != GameState::DealerTurn {
-            return Err(ShowdownError::InvalidState);
+        let mut drawn_cards = Vec::new();
+        while let Some(card) = self.dealer_play_step()? {
+            drawn_cards.push(card);
         }
+        Ok(drawn_cards)
+    }
 
-        let mut dealer = self.dealer_hand.lock();
-        dealer.reveal_hole();
+    /// Plays the dealer's hand one card at a time.
+    ///
+    /// The first call reveals the hole card. Each call draws at most one card
+    /// and returns it; once the dealer stands (or there is nothing to play
+    /// against), the round moves to round-over state and `None` is returned.
+    /// Calling this until it returns `None` is equivalent to
+    /// [`dealer_play`](Self::dealer_play).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in dealer turn state or the shoe is
+    /// empty while the dealer must draw.
+    pub fn dealer_play_step(&self) -> Result<Option<Card>, ShowdownError> {
+        let card = self.dealer_step()?;
+        self.record(LoggedAction::DealerPlayStep);
+        Ok(card)
+    }
 
-        let mut drawn_cards = Vec::new();
+    /// Plays one dealer step without recording the call.
+    fn dealer_step(&self) -> Result<Option<Card>, ShowdownError> {
+        if *self.state.lock() != GameState::DealerTurn {
+            return Err(ShowdownError::InvalidState);
+        }
 
         // If no active hands, dealer doesn't need to draw
-        drop(dealer);
         if !self.any_active_hands() {
-            *self.state.lock() = GameState::RoundOver;
-            return Ok(drawn_cards);
+            // Without a hole card the second card is never dealt
+            if !self.options().no_hole_card {
+                self.reveal_dealer_hole();
+            }
+            self.set_state(&mut self.state.lock(), GameState::RoundOver);
+            return Ok(None);
         }
 
-        // Dealer draws according to rules
-        loop {
-            let dealer = self.dealer_hand.lock();
-            let value = dealer.value();
-            let is_soft = dealer.is_soft();
-            drop(dealer);
-
-            // Stand on 17 or higher (considering soft 17 rule)
-            if value > 17 {
-                break;
-            }
-            if value == 17 && (!is_soft || self.options.stand_on_soft_17) {
-                break;
-            }
+        self.reveal_dealer_hole();
 
-            // Draw a card
+        // Without a hole card the dealer's second card is drawn first, and
+        // otherwise the dealer draws according to the rules
+        let must_draw = self.options().no_hole_card && self.dealer_hand.lock().len() < 2;
+        if must_draw || self.dealer_should_hit() {
             let card = self.draw().ok_or(ShowdownError::NoCards)?;
             self.dealer_hand.lock().add_card(card);
-            drawn_cards.push(card);
+            self.emit(GameEvent::CardDealt {
+                recipient: CardRecipient::Dealer,
+                card,
+                face_up: true,
+            });
+            return Ok(Some(card));
         }
 
-        *self.state.lock() = GameState::RoundOver;
-
-        Ok(drawn_cards)
+        self.set_state(&mut self.state.lock(), GameState::RoundOver);
+        Ok(None)
     }
 
-    /// Rounds a payout according to the rounding mode.
-    pub(super) fn round_payout(&self, amount: f64, mode: RoundingMode) -> usize {
-        round_amount(amount, mode)
+    /// Returns the total paid on a winning blackjack: the bet plus winnings at
+    /// `blackjack_pays`, or at even money on a Double Exposure table.
+    pub(super) fn blackjack_payout(&self, bet: Chips) -> Chips {
+        let options = self.options();
+        let ratio = if options.double_exposure {
+            Payout::EVEN
+        } else {
+            options.blackjack_pays
+        };
+        bet.saturating_add(ratio.winnings(bet, options.rounding_blackjack))
     }
 
     /// Performs the showdown and calculates payouts.
@@ -105,33 +132,256 @@ impl Game {
     /// This function:
     /// 1. Compares each player's hand to the dealer's hand
     /// 2. Calculates winnings based on the outcome
-    /// 3. Updates player money
+    /// 3. Credits player money hand by hand, recording each credit in the ledger
     /// 4. Returns detailed results for each player
     ///
+    /// Naturals already paid during the deal (see
+    /// `immediate_blackjack_payout`) appear in the results and the ledger as
+    /// usual, but are not credited a second time. Likewise, calling showdown
+    /// again before the round is cleared returns the same results without
+    /// crediting anyone twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in round-over state.
+    pub fn showdown(&self) -> Result<RoundResult, ShowdownError> {
+        self.showdown_with(Vec::new())
+    }
+
+    /// Performs the showdown, writing the player results into `buf`.
+    ///
+    /// `buf` is cleared first and then filled exactly as the `players` field
+    /// of [`showdown`](Self::showdown)'s result, and money is credited the
+    /// same way. Reusing one buffer across rounds avoids allocating a fresh
+    /// result list each time, which matters in tight simulation loops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in round-over state. `buf` is left
+    /// empty in that case.
+    pub fn showdown_into(&self, buf: &mut Vec<PlayerResult>) -> Result<(), ShowdownError> {
+        let result = self.showdown_with(core::mem::take(buf))?;
+        *buf = result.players;
+        Ok(())
+    }
+
+    /// Performs the showdown, collecting player results into `players`.
+    fn showdown_with(&self, players: Vec<PlayerResult>) -> Result<RoundResult, ShowdownError> {
+        let result = self.settle_with(players)?;
+        self.credit_ledger(&result.ledger);
+
+        let mut settled = self.settled_players.lock();
+        let mut newly_settled = Vec::new();
+        for player in &result.players {
+            if !settled.contains(&player.player_id) {
+                settled.push(player.player_id);
+                newly_settled.push(player);
+            }
+        }
+        drop(settled);
+        for player in newly_settled {
+            self.emit_payout(player);
+        }
+
+        self.record(LoggedAction::Showdown);
+        Ok(result)
+    }
+
+    /// Settles a single player against the dealer's final hand.
+    ///
+    /// The player's hands are resolved exactly as in
+    /// [`showdown`](Self::showdown) and only that player's money is credited.
+    /// A later showdown still reports the player in its results but does not
+    /// credit them again. Use this to pay out players who leave before the
+    /// rest of the table is settled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dealer has not finished playing (the game is
+    /// not in round-over state), the player has no hands this round, or the
+    /// player has already been settled.
+    pub fn settle_player(&self, player_id: u8) -> Result<PlayerResult, ShowdownError> {
+        let result = self.settle()?;
+        let player_result = result
+            .players
+            .into_iter()
+            .find(|player| player.player_id == player_id)
+            .ok_or(ShowdownError::PlayerNotFound)?;
+
+        if self.settled_players.lock().contains(&player_id) {
+            return Err(ShowdownError::AlreadySettled);
+        }
+
+        self.credit_ledger(
+            result
+                .ledger
+                .iter()
+                .filter(|entry| entry.player_id == player_id),
+        );
+        self.settled_players.lock().push(player_id);
+        self.emit_payout(&player_result);
+
+        self.record(LoggedAction::SettlePlayer { player_id });
+        Ok(player_result)
+    }
+
+    /// Announces a player's settlement.
+    fn emit_payout(&self, player: &PlayerResult) {
+        self.emit(GameEvent::PayoutSettled {
+            player_id: player.player_id,
+            payout: player.total_payout,
+            net: player.net,
+        });
+    }
+
+    /// Credits ledger entries to player money.
+    ///
+    /// Players settled individually and naturals paid at deal time were
+    /// already credited and are skipped.
+    fn credit_ledger<'a>(&self, entries: impl IntoIterator<Item = &'a LedgerEntry>) {
+        let prepaid = self.prepaid_hands.lock().clone();
+        let settled = self.settled_players.lock().clone();
+
+        let mut money = self.money.lock();
+        for entry in entries {
+            if settled.contains(&entry.player_id) {
+                continue;
+            }
+            // Naturals paid at deal time were already credited
+            if let LedgerSource::Hand(hand_index) = entry.source {
+                if prepaid.contains(&(entry.player_id, hand_index)) {
+                    continue;
+                }
+            }
+            if let Some(player_money) = money.get_mut(&entry.player_id) {
+                *player_money = player_money.saturating_add(entry.credit);
+            }
+        }
+        drop(money);
+    }
+
+    /// Performs the showdown without touching player money.
+    ///
+    /// The results are computed exactly as in [`showdown`](Self::showdown),
+    /// but instead of crediting the internal balances, `apply` is called once
+    /// per player, in betting order, with the player's net result for the
+    /// round. Use this when the authoritative ledger lives outside the game.
+    ///
+    /// Bets are still checked against and deducted from the internal balances
+    /// when placed; only the showdown credits are left out. Hands already paid
+    /// during the round, naturals paid at the deal and even money, were
+    /// settled internally and are left out of the net passed to `apply`.
+    ///
+    /// Every player is marked settled, so a later showdown credits nothing,
+    /// and players already settled with [`settle_player`](Self::settle_player)
+    /// are skipped.
+    ///
     /// # Errors
     ///
     /// Returns an error if the game is not in round-over state.
+    pub fn settle_external(
+        &self,
+        mut apply: impl FnMut(u8, i64),
+    ) -> Result<RoundResult, ShowdownError> {
+        let result = self.settle()?;
+        let mut newly_settled = Vec::new();
+        let mut settled = self.settled_players.lock();
+        for player in &result.players {
+            if !settled.contains(&player.player_id) {
+                settled.push(player.player_id);
+                newly_settled.push(player);
+            }
+        }
+        drop(settled);
+
+        let prepaid = self.prepaid_hands.lock().clone();
+        for player in newly_settled {
+            let prepaid_net: i64 = player
+                .hands
+                .iter()
+                .filter(|hand| prepaid.contains(&(player.player_id, hand.hand_index)))
+                .map(HandResult::net)
+                .sum();
+            apply(player.player_id, player.net.saturating_sub(prepaid_net));
+        }
+
+        self.record(LoggedAction::SettleExternal);
+        Ok(result)
+    }
+
+    /// Previews the round's results if every remaining hand stood now.
+    ///
+    /// On a copy of the game, every active hand stands, the dealer plays out
+    /// from the current shoe, and the round is settled. The real game is left
+    /// untouched: no cards are drawn from its shoe and no money moves. The
+    /// preview matches what standing every hand and settling would produce.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is not in player turn, dealer turn or
+    /// round-over state, or the shoe runs out while the dealer must draw.
+    pub fn preview_if_all_stand(&self) -> Result<RoundResult, ShowdownError> {
+        let state = *self.state.lock();
+        if !matches!(
+            state,
+            GameState::PlayerTurn | GameState::DealerTurn | GameState::RoundOver
+        ) {
+            return Err(ShowdownError::InvalidState);
+        }
+
+        let fork = self.fork();
+        if state != GameState::RoundOver {
+            let mut hands = fork.hands.lock();
+            for hand in hands.values_mut().flatten() {
+                if hand.status() == HandStatus::Active {
+                    hand.set_status(HandStatus::Stand);
+                }
+            }
+            drop(hands);
+
+            fork.set_state(&mut fork.state.lock(), GameState::DealerTurn);
+            fork.dealer_play()?;
+        }
+
+        fork.settle()
+    }
+
+    /// Computes the round results and the ledger of credits to apply.
+    fn settle(&self) -> Result<RoundResult, ShowdownError> {
+        self.settle_with(Vec::new())
+    }
+
+    /// Computes the round results into `player_results`, which is cleared
+    /// first, and the ledger of credits to apply.
     #[expect(
         clippy::significant_drop_tightening,
         reason = "locks are held for entire operation"
     )]
-    pub fn showdown(&self) -> Result<RoundResult, ShowdownError> {
+    fn settle_with(
+        &self,
+        mut player_results: Vec<PlayerResult>,
+    ) -> Result<RoundResult, ShowdownError> {
         let state = *self.state.lock();
         if state != GameState::RoundOver {
             return Err(ShowdownError::InvalidState);
         }
 
-        let dealer = self.dealer_hand.lock();
+        let options = self.options();
+
+        let dealer = self.dealer_hand.lock().clone();
         let dealer_value = dealer.value();
         let dealer_bust = dealer.is_bust();
         let dealer_blackjack = dealer.is_blackjack();
-        drop(dealer);
 
+        let even_money = self.even_money.lock().clone();
+        let side_bet_results = self.side_bet_results.lock().clone();
+        let side_bet_cards = self.side_bet_cards.lock().clone();
+        let placed_side_bets = self.side_bets.lock().clone();
         let order = self.betting_order.lock();
         let hands = self.hands.lock();
-        let mut money = self.money.lock();
 
-        let mut player_results = Vec::new();
+        player_results.clear();
+        let mut ledger = Vec::new();
 
         for &player_id in order.iter() {
             let Some(player_hands) = hands.get(&player_id) else {
@@ -139,77 +389,85 @@ impl Game {
             };
 
             let mut hand_results = Vec::new();
-            let mut total_payout: usize = 0;
-            let mut total_bet: usize = 0;
-            let mut surrender_refund_total: usize = 0;
+            let mut total_payout = Chips::ZERO;
+            let mut total_bet = Chips::ZERO;
+            let mut surrender_refund_total = Chips::ZERO;
 
             for (hand_index, hand) in player_hands.iter().enumerate() {
                 let bet = hand.bet();
-                total_bet += bet;
+                total_bet = total_bet.saturating_add(bet);
                 let player_value = hand.value();
+                let mut refund = Chips::ZERO;
 
-                let (outcome, payout) = match hand.status() {
-                    HandStatus::Surrendered => {
-                        #[expect(
-                            clippy::cast_precision_loss,
-                            reason = "f64 has sufficient precision for monetary values"
-                        )]
-                        let refund =
-                            self.round_payout((bet as f64) * 0.5, self.options.rounding_surrender);
-                        surrender_refund_total += refund;
-                        // Already refunded half during surrender
-                        (HandOutcome::Surrendered, 0)
-                    }
-                    HandStatus::Bust => {
-                        // Player busted, loses bet
-                        (HandOutcome::Lose, 0)
-                    }
-                    HandStatus::Blackjack => {
-                        if dealer_blackjack {
-                            // Push - return original bet
-                            (HandOutcome::Push, bet)
-                        } else {
-                            // Blackjack pays extra
-                            #[expect(
-                                clippy::cast_precision_loss,
-                                reason = "f64 has sufficient precision for monetary values"
-                            )]
-                            let winnings = (bet as f64) * self.options.blackjack_pays;
-                            let rounded =
-                                self.round_payout(winnings, self.options.rounding_blackjack);
-                            (HandOutcome::Blackjack, bet + rounded)
+                let bonus = if options.spanish_bonuses && !dealer_blackjack {
+                    spanish_21_bonus(hand)
+                } else {
+                    None
+                };
+                let outcome = if even_money.contains(&(player_id, hand_index)) {
+                    // Paid 1:1 during the insurance phase
+                    HandOutcome::Win
+                } else if bonus.is_some() {
+                    // A bonus 21 wins even against a dealer 21
+                    HandOutcome::Bonus
+                } else {
+                    let outcome = if options.double_exposure {
+                        // Dealer wins ties, but a player blackjack always wins
+                        match compare_blackjack(hand, &dealer, Tie::Dealer) {
+                            HandOutcome::Lose if hand.is_blackjack() => HandOutcome::Blackjack,
+                            outcome => outcome,
                         }
+                    } else {
+                        compare_blackjack(hand, &dealer, options.ties)
+                    };
+                    // A dealer 22 pushes instead of paying
+                    if outcome == HandOutcome::Win
+                        && options.push_on_dealer_22
+                        && dealer_value == 22
+                    {
+                        HandOutcome::Push
+                    } else {
+                        outcome
                     }
-                    HandStatus::Stand | HandStatus::Active => {
-                        if dealer_bust {
-                            // Dealer busts, player wins
-                            (HandOutcome::Win, bet * 2)
-                        } else if dealer_blackjack && !hand.is_from_split() && hand.len() == 2 {
-                            // Dealer has blackjack, player loses (unless they also have blackjack)
-                            (HandOutcome::Lose, 0)
-                        } else if player_value > dealer_value {
-                            // Player wins
-                            (HandOutcome::Win, bet * 2)
-                        } else if player_value < dealer_value {
-                            // Dealer wins
-                            (HandOutcome::Lose, 0)
-                        } else {
-                            // Push
-                            (HandOutcome::Push, bet)
-                        }
+                };
+                let payout = match outcome {
+                    HandOutcome::Surrendered => {
+                        let surrender_refund =
+                            Payout::HALF.winnings(bet, options.rounding_surrender);
+                        surrender_refund_total =
+                            surrender_refund_total.saturating_add(surrender_refund);
+                        refund = surrender_refund;
+                        // Already refunded half during surrender
+                        Chips::ZERO
+                    }
+                    HandOutcome::Lose => Chips::ZERO,
+                    HandOutcome::Push => bet,
+                    HandOutcome::Win | HandOutcome::Charlie => bet.saturating_mul(2),
+                    HandOutcome::Blackjack => self.blackjack_payout(bet),
+                    HandOutcome::Bonus => {
+                        let ratio = bonus.unwrap_or(Payout::EVEN);
+                        bet.saturating_add(ratio.winnings(bet, options.rounding_default))
                     }
                 };
 
-                total_payout += payout;
+                total_payout = total_payout.saturating_add(payout);
 
-                hand_results.push(HandResult {
+                let hand_result = HandResult {
                     hand_index,
                     outcome,
                     bet,
                     payout,
+                    refund,
                     player_value,
                     dealer_value,
+                };
+                ledger.push(LedgerEntry {
+                    player_id,
+                    source: LedgerSource::Hand(hand_index),
+                    credit: payout,
+                    net: hand_result.net(),
                 });
+                hand_results.push(hand_result);
             }
 
             // Handle insurance payout
@@ -218,42 +476,98 @@ impl Game {
                 .lock()
                 .get(&player_id)
                 .copied()
-                .unwrap_or(0);
-
-            let insurance_payout = if dealer_blackjack && insurance_bet > 0 {
-                // Insurance pays 2:1
-                insurance_bet * 3 // Original bet + 2x winnings
+                .unwrap_or_default();
+
+            let insurance_payout = if dealer_blackjack && !insurance_bet.is_zero() {
+                // Original bet + winnings at the insurance ratio
+                insurance_bet.saturating_add(
+                    options
+                        .insurance_pays
+                        .winnings(insurance_bet, options.rounding_insurance),
+                )
             } else {
-                0
+                Chips::ZERO
             };
 
-            total_payout += insurance_payout;
-            total_bet += insurance_bet;
+            let insurance_outcome = (!insurance_bet.is_zero()).then_some(if dealer_blackjack {
+                HandOutcome::Win
+            } else {
+                HandOutcome::Lose
+            });
 
-            // Update player money
-            if let Some(player_money) = money.get_mut(&player_id) {
-                *player_money += total_payout;
+            total_payout = total_payout.saturating_add(insurance_payout);
+            total_bet = total_bet.saturating_add(insurance_bet);
+
+            // Most side bets were settled by the opening deal; the rest wait
+            // for the dealer's final hand
+            let mut side_bets = side_bet_results
+                .get(&player_id)
+                .cloned()
+                .unwrap_or_default();
+            if let Some(cards) = side_bet_cards.get(&player_id) {
+                for &(kind, bet) in placed_side_bets.get(&player_id).into_iter().flatten() {
+                    if options.side_bet(kind).settles_at_showdown() {
+                        side_bets.push(self.side_bet_result(kind, bet, cards, &dealer, &options));
+                    }
+                }
+            }
+            for side_bet in &side_bets {
+                total_payout = total_payout.saturating_add(side_bet.payout);
+                total_bet = total_bet.saturating_add(side_bet.bet);
             }
 
-            #[expect(clippy::cast_possible_wrap, reason = "payout values fit in isize")]
-            let net =
-                (total_payout as isize + surrender_refund_total as isize) - (total_bet as isize);
+            let net = total_payout
+                .saturating_add(surrender_refund_total)
+                .signed_sub(total_bet);
 
-            player_results.push(PlayerResult {
+            let player_result = PlayerResult {
                 player_id,
                 hands: hand_results,
                 total_payout,
                 net,
                 insurance_bet,
                 insurance_payout,
-            });
+                insurance_outcome,
+                side_bets,
+            };
+
+            if !insurance_bet.is_zero() {
+                ledger.push(LedgerEntry {
+                    player_id,
+                    source: LedgerSource::Insurance,
+                    credit: insurance_payout,
+                    net: player_result.insurance_net(),
+                });
+            }
+            for side_bet in &player_result.side_bets {
+                ledger.push(LedgerEntry {
+                    player_id,
+                    source: LedgerSource::SideBet(side_bet.kind),
+                    credit: side_bet.payout,
+                    net: side_bet.net(),
+                });
+            }
+
+            player_results.push(player_result);
         }
 
+        let total_insurance_bet = player_results
+            .iter()
+            .map(|p| p.insurance_bet)
+            .fold(Chips::ZERO, Chips::saturating_add);
+        let total_insurance_payout = player_results
+            .iter()
+            .map(|p| p.insurance_payout)
+            .fold(Chips::ZERO, Chips::saturating_add);
+
         Ok(RoundResult {
             players: player_results,
+            ledger,
             dealer_value,
             dealer_bust,
             dealer_blackjack,
+            total_insurance_bet,
+            total_insurance_payout,
         })
     }
 }
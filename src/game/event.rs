@@ -0,0 +1,128 @@
+use core::sync::atomic::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::chips::Chips;
+
+use super::{Game, GameState, TurnPosition};
+
+/// Where a dealt card went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CardRecipient {
+    /// A player's hand.
+    Player {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// The dealer's hand.
+    Dealer,
+}
+
+/// Something that happened in a game, collected with
+/// [`Game::drain_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum GameEvent {
+    /// A card was dealt from the shoe to a hand.
+    CardDealt {
+        /// The hand the card went to.
+        recipient: CardRecipient,
+        /// The card.
+        card: Card,
+        /// Whether the card was dealt face up. Only the dealer's hole card
+        /// is dealt face down.
+        face_up: bool,
+    },
+    /// The turn moved on to another hand.
+    ///
+    /// The first turn of a round is not announced separately; it follows a
+    /// [`StateChanged`](Self::StateChanged) to
+    /// [`GameState::PlayerTurn`].
+    TurnAdvanced {
+        /// The new turn position.
+        turn: TurnPosition,
+    },
+    /// The game moved to another state.
+    StateChanged {
+        /// The previous state.
+        from: GameState,
+        /// The new state.
+        to: GameState,
+    },
+    /// The dealer's hole card was turned face up.
+    DealerRevealedHole {
+        /// The hole card.
+        card: Card,
+    },
+    /// A player was paid out at the showdown or by
+    /// [`Game::settle_player`].
+    PayoutSettled {
+        /// The player ID.
+        player_id: u8,
+        /// Total amount returned to the player.
+        payout: Chips,
+        /// Net win or loss for the round.
+        net: i64,
+    },
+    /// The shoe was replaced by a freshly shuffled one, or refilled from the
+    /// discards with `reshuffle_on_empty`.
+    ShoeReshuffled,
+}
+
+impl Game {
+    /// Turns event collection on or off.
+    ///
+    /// Events are off by default, so games that never drain them do not
+    /// build up a queue. Turning collection off also discards any events not
+    /// yet drained. Snapshots do not carry events or this setting.
+    pub fn set_event_recording(&self, enabled: bool) {
+        self.events_enabled.store(enabled, Ordering::SeqCst);
+        if !enabled {
+            self.events.lock().clear();
+        }
+    }
+
+    /// Removes and returns the events collected since the last call, oldest
+    /// first.
+    ///
+    /// Nothing is collected until [`set_event_recording`] turns collection
+    /// on.
+    ///
+    /// [`set_event_recording`]: Self::set_event_recording
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, Game, GameEvent, GameOptions, GameState};
+    ///
+    /// let game = Game::new(GameOptions::default(), 42);
+    /// game.set_event_recording(true);
+    /// game.join(Chips::new(100));
+    /// game.start_betting();
+    ///
+    /// assert_eq!(
+    ///     game.drain_events(),
+    ///     [GameEvent::StateChanged {
+    ///         from: GameState::WaitingForPlayers,
+    ///         to: GameState::Betting,
+    ///     }]
+    /// );
+    /// assert!(game.drain_events().is_empty());
+    /// ```
+    pub fn drain_events(&self) -> Vec<GameEvent> {
+        core::mem::take(&mut *self.events.lock())
+    }
+
+    /// Queues an event if collection is on.
+    pub(super) fn emit(&self, event: GameEvent) {
+        if self.events_enabled.load(Ordering::SeqCst) {
+            self.events.lock().push(event);
+        }
+    }
+}
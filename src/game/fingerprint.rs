@@ -0,0 +1,97 @@
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::view::DealerView;
+
+use super::Game;
+
+/// 64-bit FNV-1a, fed with fixed-width little-endian integers so the result
+/// is the same on every platform.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    fn write_chips(&mut self, value: Chips) {
+        self.write(&value.get().to_le_bytes());
+    }
+
+    fn write_card(&mut self, card: Card) {
+        self.write_u8(card.suit as u8);
+        self.write_u8(card.rank);
+    }
+}
+
+impl Game {
+    /// Returns a fingerprint of the publicly visible game state.
+    ///
+    /// The fingerprint covers the game state, the turn position, each seated
+    /// player's money, bet, insurance bet and hands (cards, statuses and
+    /// bets), and the dealer's face-up cards. Face-down cards, the shoe and
+    /// the random number generator are left out, so a client that only sees
+    /// the table can compute the same value as the server. Two games driven
+    /// through the same visible actions produce the same fingerprint.
+    ///
+    /// The hash is 64-bit FNV-1a over a fixed encoding and does not depend on
+    /// the platform or on a random seed.
+    pub fn state_fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+
+        hasher.write_u8(self.state() as u8);
+        let turn = self.current_turn();
+        hasher.write_usize(turn.player_index);
+        hasher.write_usize(turn.hand_index);
+
+        let dealer = DealerView::from(&*self.dealer_hand.lock());
+        hasher.write_usize(dealer.cards.len());
+        for card in dealer.cards.into_iter().flatten() {
+            hasher.write_card(card);
+        }
+
+        let players = self.players.lock().clone();
+        let money = self.money.lock().clone();
+        let bets = self.bets.lock().clone();
+        let insurance_bets = self.insurance_bets.lock().clone();
+        let hands = self.hands.lock();
+        hasher.write_usize(players.len());
+        for player_id in players {
+            hasher.write_u8(player_id);
+            hasher.write_chips(money.get(&player_id).copied().unwrap_or_default());
+            hasher.write_chips(bets.get(&player_id).copied().unwrap_or_default());
+            hasher.write_chips(insurance_bets.get(&player_id).copied().unwrap_or_default());
+
+            let player_hands = hands.get(&player_id).map_or(&[][..], |h| h.as_slice());
+            hasher.write_usize(player_hands.len());
+            for hand in player_hands {
+                hasher.write_u8(hand.status() as u8);
+                hasher.write_chips(hand.bet());
+                hasher.write_usize(hand.len());
+                for &card in hand.cards() {
+                    hasher.write_card(card);
+                }
+            }
+        }
+        drop(hands);
+
+        hasher.0
+    }
+}
@@ -1,17 +1,30 @@
+use alloc::vec::Vec;
+
+use crate::chips::Chips;
 use crate::error::InsuranceError;
+use crate::hand::HandStatus;
+use crate::payout::Payout;
 
-use super::{Game, GameState};
+use super::{Game, GameState, LoggedAction};
 
 impl Game {
     /// Returns whether insurance is currently being offered.
+    ///
+    /// With [`SurrenderRule::Early`](crate::SurrenderRule::Early), the
+    /// insurance phase can also open for a ten-value up card so that players
+    /// may surrender; insurance itself is not offered then.
     pub fn is_insurance_offered(&self) -> bool {
         *self.state.lock() == GameState::Insurance
+            && self.options().insurance
+            && self.dealer_hand.lock().has_ace_upcard()
     }
 
     /// Takes insurance for the specified player.
     ///
-    /// The insurance bet is half of the original bet.
-    /// If the dealer has blackjack, pays 2:1.
+    /// The insurance bet is half of the original bet, rounded with
+    /// `rounding_insurance` when the bet is odd; to bet less, use
+    /// [`take_insurance_amount`](Self::take_insurance_amount).
+    /// If the dealer has blackjack, pays `insurance_pays` (2:1 by default).
     ///
     /// # Errors
     ///
@@ -21,12 +34,43 @@ impl Game {
     /// - The player is not found or has not bet
     /// - The player has insufficient funds
     /// - The player has already made an insurance decision
-    pub fn take_insurance(&self, player_id: u8) -> Result<usize, InsuranceError> {
+    pub fn take_insurance(&self, player_id: u8) -> Result<Chips, InsuranceError> {
+        let insurance_bet = self.insure(player_id, None)?;
+        self.record(LoggedAction::TakeInsurance { player_id });
+        Ok(insurance_bet)
+    }
+
+    /// Takes insurance for less than half the original bet.
+    ///
+    /// `amount` may be anything from 1 up to half the original bet (rounded
+    /// with `rounding_insurance`), which is what
+    /// [`take_insurance`](Self::take_insurance) bets. It
+    /// is settled like any insurance bet: if the dealer has blackjack it pays
+    /// `insurance_pays` (2:1 by default).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsuranceError::InvalidAmount`] if `amount` is zero or more
+    /// than half the original bet, and otherwise the same errors as
+    /// [`take_insurance`](Self::take_insurance).
+    pub fn take_insurance_amount(
+        &self,
+        player_id: u8,
+        amount: Chips,
+    ) -> Result<Chips, InsuranceError> {
+        let insurance_bet = self.insure(player_id, Some(amount))?;
+        self.record(LoggedAction::TakeInsuranceAmount { player_id, amount });
+        Ok(insurance_bet)
+    }
+
+    /// Takes insurance without recording the call, betting `amount` or, if
+    /// `None`, half the original bet.
+    fn insure(&self, player_id: u8, amount: Option<Chips>) -> Result<Chips, InsuranceError> {
         if *self.state.lock() != GameState::Insurance {
             return Err(InsuranceError::InvalidState);
         }
 
-        if !self.options.insurance {
+        if !self.options().insurance || !self.dealer_hand.lock().has_ace_upcard() {
             return Err(InsuranceError::NotOffered);
         }
 
@@ -43,19 +87,21 @@ impl Game {
             .copied()
             .ok_or(InsuranceError::NoBet)?;
 
-        let insurance_bet = original_bet / 2;
+        let full_insurance = self.insurance_stake(original_bet);
+        let insurance_bet = match amount {
+            None => full_insurance,
+            Some(amount) if !amount.is_zero() && amount <= full_insurance => amount,
+            Some(_) => return Err(InsuranceError::InvalidAmount),
+        };
 
         // Check if player has enough money
         let mut money = self.money.lock();
         let player_money = money
             .get_mut(&player_id)
             .ok_or(InsuranceError::PlayerNotFound)?;
-
-        if *player_money < insurance_bet {
-            return Err(InsuranceError::InsufficientFunds);
-        }
-
-        *player_money -= insurance_bet;
+        *player_money = player_money
+            .checked_sub(insurance_bet)
+            .ok_or(InsuranceError::InsufficientFunds)?;
         drop(money);
 
         // Record insurance bet
@@ -65,6 +111,127 @@ impl Game {
         Ok(insurance_bet)
     }
 
+    /// Takes insurance for each of the listed players.
+    ///
+    /// Returns one result per player, in the order given, as
+    /// [`take_insurance`](Self::take_insurance) would. A failure for one
+    /// player does not stop the others from being insured.
+    pub fn take_insurance_for(&self, player_ids: &[u8]) -> Vec<Result<Chips, InsuranceError>> {
+        player_ids
+            .iter()
+            .map(|&player_id| self.take_insurance(player_id))
+            .collect()
+    }
+
+    /// Returns whether the player can cover an insurance bet on their current bet.
+    ///
+    /// Returns `false` if the player is not found or has not bet.
+    pub fn can_afford_insurance(&self, player_id: u8) -> bool {
+        let Some(bet) = self.bets.lock().get(&player_id).copied() else {
+            return false;
+        };
+        let stake = self.insurance_stake(bet);
+        self.money
+            .lock()
+            .get(&player_id)
+            .is_some_and(|&money| money >= stake)
+    }
+
+    /// Returns the full insurance bet on a wager: half of it, rounded with
+    /// `rounding_insurance`.
+    fn insurance_stake(&self, bet: Chips) -> Chips {
+        Payout::HALF.winnings(bet, self.options().rounding_insurance)
+    }
+
+    /// Takes even money when the player holds a natural, and insurance
+    /// otherwise.
+    ///
+    /// A natural in the player's first hand is settled at 1:1 now with
+    /// [`take_even_money`](Self::take_even_money), whatever the blackjack and
+    /// insurance payouts. Any other hand takes regular insurance with
+    /// [`take_insurance`](Self::take_insurance).
+    ///
+    /// Returns the amount credited for even money, or the insurance bet
+    /// amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`take_even_money`](Self::take_even_money)
+    /// or [`take_insurance`](Self::take_insurance).
+    pub fn take_even_money_or_insurance(&self, player_id: u8) -> Result<Chips, InsuranceError> {
+        if self.player_has_natural(player_id, 0) {
+            self.take_even_money(player_id, 0)
+        } else {
+            self.take_insurance(player_id)
+        }
+    }
+
+    /// Settles a player blackjack at 1:1 before the dealer checks for
+    /// blackjack.
+    ///
+    /// When the dealer shows an ace, a player holding a natural may take a
+    /// guaranteed win equal to the bet instead of risking a push. The stake
+    /// and the win are credited now, the player's insurance decision is taken
+    /// as made, and the showdown reports the hand as a
+    /// [`Win`](crate::HandOutcome::Win) without crediting it again. The
+    /// amount credited is also reported by
+    /// [`immediate_payout`](Self::immediate_payout).
+    ///
+    /// Returns the amount credited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The game is not in the insurance state
+    /// - Insurance is not offered (the dealer does not show an ace)
+    /// - The player is not found
+    /// - The hand is not a blackjack
+    /// - The player has already made an insurance decision
+    pub fn take_even_money(
+        &self,
+        player_id: u8,
+        hand_index: usize,
+    ) -> Result<Chips, InsuranceError> {
+        if *self.state.lock() != GameState::Insurance {
+            return Err(InsuranceError::InvalidState);
+        }
+
+        if !self.is_insurance_offered() {
+            return Err(InsuranceError::NotOffered);
+        }
+
+        let bet = self
+            .hands
+            .lock()
+            .get(&player_id)
+            .ok_or(InsuranceError::PlayerNotFound)?
+            .get(hand_index)
+            .filter(|hand| hand.is_blackjack())
+            .ok_or(InsuranceError::NotBlackjack)?
+            .bet();
+
+        let mut decided = self.insurance_decided.lock();
+        if decided.contains(&player_id) {
+            return Err(InsuranceError::AlreadyDecided);
+        }
+        decided.push(player_id);
+        drop(decided);
+
+        let payout = bet.saturating_mul(2);
+        if let Some(money) = self.money.lock().get_mut(&player_id) {
+            *money = money.saturating_add(payout);
+        }
+        self.immediate_payouts.lock().insert(player_id, payout);
+        self.prepaid_hands.lock().push((player_id, hand_index));
+        self.even_money.lock().push((player_id, hand_index));
+
+        self.record(LoggedAction::TakeEvenMoney {
+            player_id,
+            hand_index,
+        });
+        Ok(payout)
+    }
+
     /// Declines insurance for the specified player.
     ///
     /// # Errors
@@ -74,6 +241,13 @@ impl Game {
     /// - The player has not bet
     /// - The player has already made an insurance decision
     pub fn decline_insurance(&self, player_id: u8) -> Result<(), InsuranceError> {
+        self.decline(player_id)?;
+        self.record(LoggedAction::DeclineInsurance { player_id });
+        Ok(())
+    }
+
+    /// Declines insurance without recording the call.
+    fn decline(&self, player_id: u8) -> Result<(), InsuranceError> {
         if *self.state.lock() != GameState::Insurance {
             return Err(InsuranceError::InvalidState);
         }
@@ -94,6 +268,80 @@ impl Game {
         Ok(())
     }
 
+    /// Changes a player's insurance decision before insurance is finished.
+    ///
+    /// Any insurance bet already placed is refunded first. With `take` set,
+    /// insurance is then taken as with [`take_insurance`](Self::take_insurance)
+    /// and the new insurance bet is returned; otherwise insurance is declined
+    /// and the refunded amount is returned. A player who has not decided yet
+    /// simply makes the decision.
+    ///
+    /// Taking [even money](Self::take_even_money) or surrendering early
+    /// settles the decision for good, and cannot be revised.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`take_insurance`](Self::take_insurance) or
+    /// [`decline_insurance`](Self::decline_insurance). An
+    /// [`InsuranceError::AlreadyDecided`] error means the player took even
+    /// money or surrendered early. On error the previous decision stands.
+    pub fn revise_insurance(&self, player_id: u8, take: bool) -> Result<Chips, InsuranceError> {
+        if *self.state.lock() != GameState::Insurance {
+            return Err(InsuranceError::InvalidState);
+        }
+
+        let took_even_money = self
+            .even_money
+            .lock()
+            .iter()
+            .any(|&(id, _)| id == player_id);
+        let surrendered = self.hands.lock().get(&player_id).is_some_and(|hands| {
+            hands
+                .iter()
+                .any(|hand| hand.status() == HandStatus::Surrendered)
+        });
+        if took_even_money || surrendered {
+            return Err(InsuranceError::AlreadyDecided);
+        }
+
+        let was_decided = {
+            let mut decided = self.insurance_decided.lock();
+            let was_decided = decided.contains(&player_id);
+            decided.retain(|&id| id != player_id);
+            was_decided
+        };
+        let refund = self
+            .insurance_bets
+            .lock()
+            .remove(&player_id)
+            .unwrap_or_default();
+        if let Some(money) = self.money.lock().get_mut(&player_id) {
+            *money = money.saturating_add(refund);
+        }
+
+        let revised = if take {
+            self.insure(player_id, None)
+        } else {
+            self.decline(player_id).map(|()| refund)
+        };
+
+        if revised.is_err() && was_decided {
+            // Restore the previous decision, re-placing a refunded bet.
+            if let Some(money) = self.money.lock().get_mut(&player_id) {
+                *money = money.saturating_sub(refund);
+            }
+            if !refund.is_zero() {
+                self.insurance_bets.lock().insert(player_id, refund);
+            }
+            self.insurance_decided.lock().push(player_id);
+        }
+
+        if revised.is_ok() {
+            self.record(LoggedAction::ReviseInsurance { player_id, take });
+        }
+        revised
+    }
+
     /// Checks if all players have made their insurance decision.
     pub fn all_insurance_decided(&self) -> bool {
         let order = self.betting_order.lock();
@@ -104,7 +352,8 @@ impl Game {
     /// Finishes the insurance phase and moves to player turns.
     ///
     /// This should be called after all players have made their insurance decision.
-    /// If the dealer has blackjack, the round ends immediately.
+    /// If the dealer has blackjack, the round ends immediately; if no hand is
+    /// left to play, the game moves on to the dealer's turn.
     ///
     /// Returns `true` if the dealer has blackjack (round ends), `false` otherwise.
     ///
@@ -116,23 +365,29 @@ impl Game {
             return Err(InsuranceError::InvalidState);
         }
 
+        let dealer_has_blackjack = self.resolve_insurance();
+        self.record(LoggedAction::FinishInsurance);
+        Ok(dealer_has_blackjack)
+    }
+
+    /// Ends the insurance phase, returning whether the dealer has blackjack.
+    pub(super) fn resolve_insurance(&self) -> bool {
         let dealer_has_blackjack = self.dealer_hand.lock().is_blackjack();
 
         if dealer_has_blackjack {
             // Reveal dealer's hole card
-            self.dealer_hand.lock().reveal_hole();
-            *self.state.lock() = GameState::RoundOver;
-            Ok(true)
+            self.reveal_dealer_hole();
+            self.set_state(&mut self.state.lock(), GameState::RoundOver);
         } else {
             // Continue to player turns
-            self.advance_if_current_inactive();
-            *self.state.lock() = GameState::PlayerTurn;
-            Ok(false)
+            self.begin_player_turns(&mut self.state.lock());
         }
+
+        dealer_has_blackjack
     }
 
     /// Returns the insurance bet for the specified player.
-    pub fn get_insurance_bet(&self, player_id: u8) -> Option<usize> {
+    pub fn get_insurance_bet(&self, player_id: u8) -> Option<Chips> {
         self.insurance_bets.lock().get(&player_id).copied()
     }
 }
@@ -0,0 +1,331 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::error::ReplayError;
+use crate::options::GameOptions;
+use crate::side_bet::SideBetKind;
+
+use super::{Game, GameState};
+
+/// A state-changing call recorded in a game's action log.
+///
+/// Each variant names the [`Game`] method it records and holds that call's
+/// arguments. Composite helpers such as [`Game::play_round`] and
+/// [`Game::dealer_play`] are recorded as the individual calls they make.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum LoggedAction {
+    /// [`Game::join`].
+    Join {
+        /// Starting money.
+        money: Chips,
+    },
+    /// [`Game::leave`].
+    Leave {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::start_betting`].
+    StartBetting,
+    /// [`Game::bet`].
+    Bet {
+        /// The player ID.
+        player_id: u8,
+        /// Bet amount.
+        amount: Chips,
+    },
+    /// [`Game::place_side_bet`].
+    PlaceSideBet {
+        /// The player ID.
+        player_id: u8,
+        /// The side bet.
+        kind: SideBetKind,
+        /// Bet amount.
+        amount: Chips,
+    },
+    /// [`Game::cancel_bet`].
+    CancelBet {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::deal`].
+    Deal,
+    /// [`Game::deal_from`].
+    DealFrom {
+        /// The cards supplied for the round, first card first.
+        cards: Vec<Card>,
+    },
+    /// [`Game::hit`].
+    Hit {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::stand`].
+    Stand {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::double_down`].
+    DoubleDown {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::split`].
+    Split {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::surrender`].
+    Surrender {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::take_insurance`].
+    TakeInsurance {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::take_insurance_amount`].
+    TakeInsuranceAmount {
+        /// The player ID.
+        player_id: u8,
+        /// Insurance bet amount.
+        amount: Chips,
+    },
+    /// [`Game::decline_insurance`].
+    DeclineInsurance {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::take_even_money`].
+    TakeEvenMoney {
+        /// The player ID.
+        player_id: u8,
+        /// The hand index.
+        hand_index: usize,
+    },
+    /// [`Game::switch`].
+    Switch {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::revise_insurance`].
+    ReviseInsurance {
+        /// The player ID.
+        player_id: u8,
+        /// Whether insurance is taken.
+        take: bool,
+    },
+    /// [`Game::finish_insurance`].
+    FinishInsurance,
+    /// [`Game::dealer_play_step`].
+    DealerPlayStep,
+    /// [`Game::showdown`] or [`Game::showdown_into`].
+    Showdown,
+    /// [`Game::settle_player`].
+    SettlePlayer {
+        /// The player ID.
+        player_id: u8,
+    },
+    /// [`Game::settle_external`].
+    SettleExternal,
+    /// [`Game::clear_round`].
+    ClearRound,
+    /// [`Game::clear_round_keep_bets`].
+    ClearRoundKeepBets,
+    /// [`Game::reshuffle`].
+    Reshuffle,
+    /// [`Game::reseed_for_round`].
+    ReseedForRound {
+        /// The round number.
+        round: u64,
+    },
+    /// [`Game::update_options`].
+    SetOptions {
+        /// The options in effect after the call.
+        options: Box<GameOptions>,
+    },
+    /// [`Game::set_penetration`].
+    SetPenetration {
+        /// The new penetration.
+        penetration: f64,
+    },
+    /// [`Game::transition`].
+    Transition {
+        /// The state moved to.
+        to: GameState,
+    },
+    /// [`Game::adjust_funds`].
+    AdjustFunds {
+        /// The player ID.
+        player_id: u8,
+        /// The amount added (negative to remove).
+        delta: i64,
+    },
+    /// [`Game::draw_card`].
+    DrawCard,
+    /// [`Game::remove_card`].
+    RemoveCard {
+        /// The card removed from the shoe.
+        card: Card,
+    },
+}
+
+impl Game {
+    /// Returns every successful state-changing call made on this game, oldest
+    /// first.
+    ///
+    /// Calls that return an error are not recorded. Passing the log to
+    /// [`replay`](Self::replay) with the options and seed the game was
+    /// created with rebuilds the same game.
+    pub fn action_log(&self) -> Vec<LoggedAction> {
+        self.action_log.lock().clone()
+    }
+
+    /// Rebuilds a game by replaying an action log.
+    ///
+    /// A game is created with [`try_new`](Self::try_new) from `options` and
+    /// `seed`, and each action is applied in order. Because shuffles are
+    /// seeded, the result matches the game the log was taken from, provided
+    /// that game was created with [`new`](Self::new) or `try_new` from the
+    /// same options and seed. The replayed game records the same log.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplayError::Options`] if the options are invalid, or
+    /// [`ReplayError::ActionFailed`] with the index of the first action that
+    /// fails, which means the log does not belong to this game.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, Game, GameOptions};
+    ///
+    /// let options = GameOptions::default();
+    /// let game = Game::new(options.clone(), 42);
+    /// let player = game.join(Chips::new(100));
+    /// game.start_betting();
+    /// game.bet(player, Chips::new(10)).unwrap();
+    /// game.deal().unwrap();
+    ///
+    /// let replayed = Game::replay(options, 42, &game.action_log()).unwrap();
+    /// assert_eq!(replayed.state_fingerprint(), game.state_fingerprint());
+    /// ```
+    pub fn replay(
+        options: GameOptions,
+        seed: u64,
+        actions: &[LoggedAction],
+    ) -> Result<Self, ReplayError> {
+        let game = Self::try_new(options, seed)?;
+        for (index, action) in actions.iter().enumerate() {
+            if !game.apply_logged(action) {
+                return Err(ReplayError::ActionFailed { index });
+            }
+        }
+        Ok(game)
+    }
+
+    /// Appends a successful call to the action log.
+    pub(super) fn record(&self, action: LoggedAction) {
+        self.action_log.lock().push(action);
+    }
+
+    /// Repeats a recorded call, returning whether it succeeded.
+    fn apply_logged(&self, action: &LoggedAction) -> bool {
+        match *action {
+            LoggedAction::Join { money } => {
+                self.join(money);
+                true
+            }
+            LoggedAction::Leave { player_id } => {
+                self.leave(player_id);
+                true
+            }
+            LoggedAction::StartBetting => {
+                self.start_betting();
+                true
+            }
+            LoggedAction::Bet { player_id, amount } => self.bet(player_id, amount).is_ok(),
+            LoggedAction::PlaceSideBet {
+                player_id,
+                kind,
+                amount,
+            } => self.place_side_bet(player_id, kind, amount).is_ok(),
+            LoggedAction::CancelBet { player_id } => self.cancel_bet(player_id).is_ok(),
+            LoggedAction::Deal => self.deal().is_ok(),
+            LoggedAction::DealFrom { ref cards } => self.deal_from(cards).is_ok(),
+            LoggedAction::Hit {
+                player_id,
+                hand_index,
+            } => self.hit(player_id, hand_index).is_ok(),
+            LoggedAction::Stand {
+                player_id,
+                hand_index,
+            } => self.stand(player_id, hand_index).is_ok(),
+            LoggedAction::DoubleDown {
+                player_id,
+                hand_index,
+            } => self.double_down(player_id, hand_index).is_ok(),
+            LoggedAction::Split {
+                player_id,
+                hand_index,
+            } => self.split(player_id, hand_index).is_ok(),
+            LoggedAction::Surrender {
+                player_id,
+                hand_index,
+            } => self.surrender(player_id, hand_index).is_ok(),
+            LoggedAction::TakeInsurance { player_id } => self.take_insurance(player_id).is_ok(),
+            LoggedAction::TakeInsuranceAmount { player_id, amount } => {
+                self.take_insurance_amount(player_id, amount).is_ok()
+            }
+            LoggedAction::DeclineInsurance { player_id } => {
+                self.decline_insurance(player_id).is_ok()
+            }
+            LoggedAction::TakeEvenMoney {
+                player_id,
+                hand_index,
+            } => self.take_even_money(player_id, hand_index).is_ok(),
+            LoggedAction::Switch { player_id } => self.switch(player_id).is_ok(),
+            LoggedAction::ReviseInsurance { player_id, take } => {
+                self.revise_insurance(player_id, take).is_ok()
+            }
+            LoggedAction::FinishInsurance => self.finish_insurance().is_ok(),
+            LoggedAction::DealerPlayStep => self.dealer_play_step().is_ok(),
+            LoggedAction::Showdown => self.showdown().is_ok(),
+            LoggedAction::SettlePlayer { player_id } => self.settle_player(player_id).is_ok(),
+            LoggedAction::SettleExternal => self.settle_external(|_, _| {}).is_ok(),
+            LoggedAction::ClearRound => {
+                self.clear_round();
+                true
+            }
+            LoggedAction::ClearRoundKeepBets => self.clear_round_keep_bets().is_ok(),
+            LoggedAction::Reshuffle => self.reshuffle().is_ok(),
+            LoggedAction::ReseedForRound { round } => self.reseed_for_round(round).is_ok(),
+            LoggedAction::SetOptions { ref options } => self
+                .update_options(|current| current.clone_from(options))
+                .is_ok(),
+            LoggedAction::SetPenetration { penetration } => {
+                self.set_penetration(penetration).is_ok()
+            }
+            LoggedAction::Transition { to } => self.transition(to).is_ok(),
+            LoggedAction::AdjustFunds { player_id, delta } => {
+                self.adjust_funds(player_id, delta).is_ok()
+            }
+            LoggedAction::DrawCard => self.draw_card().is_some(),
+            LoggedAction::RemoveCard { card } => self.remove_card(card).is_ok(),
+        }
+    }
+}
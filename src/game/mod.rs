@@ -1,40 +1,59 @@
 //! Game engine and state management.
 
-use core::sync::atomic::{AtomicU8, Ordering};
+use core::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
 
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-#[cfg(all(not(feature = "std"), feature = "alloc"))]
-use hashbrown::HashMap;
 use rand::SeedableRng;
 use rand::seq::SliceRandom;
 use rand_chacha::ChaCha8Rng;
-#[cfg(feature = "std")]
-use std::collections::HashMap;
 
 use crate::sync::Mutex;
 
-use crate::card::{Card, DECK_SIZE, Suit};
-use crate::error::ReshuffleError;
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::error::{BetError, FundsError, OptionsError, ReshuffleError, ShoeError, StateError};
 use crate::hand::{DealerHand, Hand, HandStatus};
-use crate::options::GameOptions;
+use crate::options::{DeckComposition, GameOptions};
+use crate::result::SideBetResult;
+use crate::side_bet::SideBetKind;
+use crate::view::{DealerView, PlayerView, SpectatorView};
 
 mod actions;
 mod bet;
 mod dealer;
+mod event;
+mod fingerprint;
 mod insurance;
+mod log;
+mod round;
+mod snapshot;
 pub mod state;
 
+pub use actions::{Action, ActionSet, HitOutcome, SplitDenyReason, StandingHint};
+pub use event::{CardRecipient, GameEvent};
+pub use log::LoggedAction;
+pub use round::PlayerDecision;
+pub use snapshot::GameSnapshot;
 pub use state::{GameState, TurnPosition};
 
 /// A blackjack game engine that manages players, betting, and round flow.
 ///
 /// The game owns the shoe, player state, and dealer state. Use [`GameOptions`]
 /// to configure rules such as decks, doubling rules, and payout rounding.
+///
+/// Player-keyed state is stored in ordered maps, so iteration order is by
+/// player ID and identical across `std` and `no_std` builds.
 pub struct Game {
     /// Cards in the shoe.
     pub decks: Mutex<Vec<Card>>,
+    /// Externally supplied cards that replace the shoe for the current round.
+    round_shoe: Mutex<Option<Vec<Card>>>,
+    /// Cards drawn from the shoe and discarded since the last reshuffle.
+    discards: Mutex<Vec<Card>>,
     /// Game options.
-    pub options: GameOptions,
+    pub options: Mutex<GameOptions>,
     /// Current game state.
     pub state: Mutex<GameState>,
     /// Next player ID to assign.
@@ -42,28 +61,68 @@ pub struct Game {
     /// Active player IDs.
     pub players: Mutex<Vec<u8>>,
     /// Player money (`player_id` -> money amount).
-    pub money: Mutex<HashMap<u8, usize>>,
+    pub money: Mutex<BTreeMap<u8, Chips>>,
     /// Player bets for current round (`player_id` -> bet amount).
-    pub bets: Mutex<HashMap<u8, usize>>,
+    pub bets: Mutex<BTreeMap<u8, Chips>>,
     /// Player hands (`player_id` -> list of hands for splits).
-    pub hands: Mutex<HashMap<u8, Vec<Hand>>>,
+    pub hands: Mutex<BTreeMap<u8, Vec<Hand>>>,
     /// Dealer's hand.
     pub dealer_hand: Mutex<DealerHand>,
+    /// Dealer's hand from the most recently cleared round.
+    last_dealer_hand: Mutex<Option<DealerHand>>,
     /// Ordered list of players who bet this round.
     betting_order: Mutex<Vec<u8>>,
     /// Current turn position.
     current_turn: Mutex<TurnPosition>,
     /// Insurance bets (`player_id` -> insurance bet amount).
-    insurance_bets: Mutex<HashMap<u8, usize>>,
+    insurance_bets: Mutex<BTreeMap<u8, Chips>>,
     /// Players who have made their insurance decision.
     insurance_decided: Mutex<Vec<u8>>,
+    /// Naturals paid during the deal (`player_id` -> amount credited).
+    immediate_payouts: Mutex<BTreeMap<u8, Chips>>,
+    /// Hands whose natural was paid before the showdown (`player_id`, hand
+    /// index).
+    prepaid_hands: Mutex<Vec<(u8, usize)>>,
+    /// Hands that took even money this round (`player_id`, hand index).
+    even_money: Mutex<Vec<(u8, usize)>>,
+    /// Side bets placed for the next deal (`player_id` -> kind and amount).
+    side_bets: Mutex<BTreeMap<u8, Vec<(SideBetKind, Chips)>>>,
+    /// Side bets settled by this round's deal (`player_id` -> results).
+    side_bet_results: Mutex<BTreeMap<u8, Vec<SideBetResult>>>,
+    /// Opening cards of players with side bets settled at showdown.
+    side_bet_cards: Mutex<BTreeMap<u8, Vec<Card>>>,
+    /// Players settled individually this round.
+    settled_players: Mutex<Vec<u8>>,
+    /// Seed the game was created with, used to derive per-round seeds.
+    base_seed: u64,
     /// Random number generator.
     rng: Mutex<ChaCha8Rng>,
+    /// Callback fired when the shoe crosses the penetration threshold.
+    low_shoe_callback: Mutex<Option<Box<dyn FnMut() + Send>>>,
+    /// Whether the low-shoe callback already fired for the current shoe.
+    low_shoe_notified: AtomicBool,
+    /// Whether a deal is in progress, so the low-shoe callback must wait.
+    dealing: AtomicBool,
+    /// Whether the low-shoe callback is waiting for the deal to finish.
+    low_shoe_pending: AtomicBool,
+    /// Cards drawn since the current round was dealt.
+    cards_dealt: AtomicUsize,
+    /// Successful state-changing calls, oldest first.
+    action_log: Mutex<Vec<LoggedAction>>,
+    /// Whether events are being collected.
+    events_enabled: AtomicBool,
+    /// Events collected since the last drain, oldest first.
+    events: Mutex<Vec<GameEvent>>,
 }
 
 impl Game {
     /// Creates a new game with the given seed.
     ///
+    /// # Panics
+    ///
+    /// Panics if `options` fail [`GameOptions::validate`], for example with
+    /// zero decks.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -75,40 +134,189 @@ impl Game {
     /// ```
     #[must_use]
     pub fn new(options: GameOptions, seed: u64) -> Self {
+        match Self::try_new(options, seed) {
+            Ok(game) => game,
+            Err(err) => panic!("invalid game options: {err}"),
+        }
+    }
+
+    /// Creates a new game with the given seed, validating the options first.
+    ///
+    /// Use this instead of [`new`](Self::new) when the options come from
+    /// outside the program, such as user input or a configuration file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`GameOptions::validate`] if the options are
+    /// invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Game, GameOptions, OptionsError};
+    ///
+    /// assert!(Game::try_new(GameOptions::default(), 42).is_ok());
+    /// assert_eq!(
+    ///     Game::try_new(GameOptions::default().with_decks(0), 42).err(),
+    ///     Some(OptionsError::NoDecks)
+    /// );
+    /// ```
+    pub fn try_new(options: GameOptions, seed: u64) -> Result<Self, OptionsError> {
+        options.validate()?;
         let mut rng = ChaCha8Rng::seed_from_u64(seed);
-        let decks = Self::create_shoe(options.decks, &mut rng);
+        let decks = Self::create_shoe(options.decks, options.deck_composition, &mut rng);
 
-        Self {
+        Ok(Self {
             decks: Mutex::new(decks),
-            options,
+            round_shoe: Mutex::new(None),
+            discards: Mutex::new(Vec::new()),
+            options: Mutex::new(options),
             state: Mutex::new(GameState::WaitingForPlayers),
             next_id: AtomicU8::new(0),
             players: Mutex::new(Vec::new()),
-            money: Mutex::new(HashMap::new()),
-            bets: Mutex::new(HashMap::new()),
-            hands: Mutex::new(HashMap::new()),
+            money: Mutex::new(BTreeMap::new()),
+            bets: Mutex::new(BTreeMap::new()),
+            hands: Mutex::new(BTreeMap::new()),
             dealer_hand: Mutex::new(DealerHand::new()),
+            last_dealer_hand: Mutex::new(None),
             betting_order: Mutex::new(Vec::new()),
             current_turn: Mutex::new(TurnPosition {
                 player_index: 0,
                 hand_index: 0,
             }),
-            insurance_bets: Mutex::new(HashMap::new()),
+            insurance_bets: Mutex::new(BTreeMap::new()),
             insurance_decided: Mutex::new(Vec::new()),
+            immediate_payouts: Mutex::new(BTreeMap::new()),
+            prepaid_hands: Mutex::new(Vec::new()),
+            even_money: Mutex::new(Vec::new()),
+            side_bets: Mutex::new(BTreeMap::new()),
+            side_bet_results: Mutex::new(BTreeMap::new()),
+            side_bet_cards: Mutex::new(BTreeMap::new()),
+            settled_players: Mutex::new(Vec::new()),
+            base_seed: seed,
             rng: Mutex::new(rng),
+            low_shoe_callback: Mutex::new(None),
+            low_shoe_notified: AtomicBool::new(false),
+            dealing: AtomicBool::new(false),
+            low_shoe_pending: AtomicBool::new(false),
+            cards_dealt: AtomicUsize::new(0),
+            action_log: Mutex::new(Vec::new()),
+            events_enabled: AtomicBool::new(false),
+            events: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Returns an independent copy of the game for looking ahead.
+    ///
+    /// The copy has no low-shoe callback, and drawing from it never fires
+    /// this game's callback.
+    fn fork(&self) -> Self {
+        let fork = Self::restore(self.snapshot());
+        fork.low_shoe_notified.store(true, Ordering::SeqCst);
+        fork
+    }
+
+    /// Creates a new game whose shoe is in canonical, unshuffled order.
+    ///
+    /// Cards are drawn deck by deck, each in
+    /// [`standard_deck`](crate::standard_deck) order (or
+    /// [`spanish_deck`](crate::spanish_deck) order for Spanish decks): the
+    /// ace of hearts comes out first and the king of spades last. Only the
+    /// initial shoe is ordered; a later [`reshuffle`](Self::reshuffle)
+    /// shuffles as usual, using seed 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Card, Game, GameOptions, Suit};
+    ///
+    /// let game = Game::new_ordered(GameOptions::default());
+    /// assert_eq!(game.draw_card(), Some(Card::new(Suit::Hearts, 1)));
+    /// assert_eq!(game.draw_card(), Some(Card::new(Suit::Hearts, 2)));
+    /// ```
+    #[must_use]
+    pub fn new_ordered(options: GameOptions) -> Self {
+        let game = Self::new(options, 0);
+        let options = game.options();
+        let deck = options.deck_composition.deck();
+        let mut decks = game.decks.lock();
+        decks.clear();
+        for _ in 0..options.decks {
+            decks.extend(deck.iter().rev());
         }
+        drop(decks);
+        game
     }
 
-    /// Creates and shuffles a shoe with the specified number of decks.
-    fn create_shoe(num_decks: u8, rng: &mut ChaCha8Rng) -> Vec<Card> {
-        let mut cards = Vec::with_capacity(num_decks as usize * DECK_SIZE);
+    /// Creates a new game whose shoes are derived from a base seed per round.
+    ///
+    /// The initial shoe is the one for round 0, as produced by
+    /// [`reseed_for_round(0)`](Self::reseed_for_round). Any later round can be
+    /// reproduced on its own by reseeding for its number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Game, GameOptions};
+    ///
+    /// let game = Game::new_with_round_seeding(GameOptions::default(), 42);
+    /// game.reseed_for_round(57).unwrap();
+    /// ```
+    #[must_use]
+    pub fn new_with_round_seeding(options: GameOptions, base_seed: u64) -> Self {
+        let game = Self::new(options, base_seed);
+        *game.rng.lock() = ChaCha8Rng::seed_from_u64(Self::round_seed(base_seed, 0));
+        let options = game.options();
+        *game.decks.lock() = Self::create_shoe(
+            options.decks,
+            options.deck_composition,
+            &mut game.rng.lock(),
+        );
+        game
+    }
+
+    /// Reseeds the random number generator for a round and reshuffles the shoe.
+    ///
+    /// The generator is seeded with `base_seed ^ splitmix64(round)`, where
+    /// `base_seed` is the seed the game was created with and `splitmix64` is
+    /// the standard `SplitMix64` finalizer applied to `round` (add
+    /// `0x9E3779B97F4A7C15`, then mix with the constants `0xBF58476D1CE4E5B9`
+    /// and `0x94D049BB133111EB`). This derivation is part of the public
+    /// contract and will not change, so the same base seed and round always
+    /// produce the same shoe.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is in progress (not in `WaitingForPlayers`
+    /// or Betting state).
+    pub fn reseed_for_round(&self, round: u64) -> Result<(), ReshuffleError> {
+        let state = *self.state.lock();
+        if state != GameState::WaitingForPlayers && state != GameState::Betting {
+            return Err(ReshuffleError::InvalidState);
+        }
+
+        *self.rng.lock() = ChaCha8Rng::seed_from_u64(Self::round_seed(self.base_seed, round));
+        self.shuffle_new_shoe();
+        self.record(LoggedAction::ReseedForRound { round });
+        Ok(())
+    }
+
+    /// Derives the seed for a round from the base seed.
+    const fn round_seed(base_seed: u64, round: u64) -> u64 {
+        let mut z = round.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        base_seed ^ (z ^ (z >> 31))
+    }
+
+    /// Creates and shuffles a shoe with the specified number and kind of
+    /// decks.
+    fn create_shoe(num_decks: u8, composition: DeckComposition, rng: &mut ChaCha8Rng) -> Vec<Card> {
+        let deck = composition.deck();
+        let mut cards = Vec::with_capacity(num_decks as usize * deck.len());
 
         for _ in 0..num_decks {
-            for suit in [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades] {
-                for rank in 1..=13 {
-                    cards.push(Card::new(suit, rank));
-                }
-            }
+            cards.extend_from_slice(&deck);
         }
 
         cards.shuffle(rng);
@@ -120,20 +328,98 @@ impl Game {
     /// # Errors
     ///
     /// Returns an error if the game is in progress (not in `WaitingForPlayers` or Betting state).
-    #[expect(
-        clippy::significant_drop_tightening,
-        reason = "locks are held for entire operation"
-    )]
     pub fn reshuffle(&self) -> Result<(), ReshuffleError> {
         let state = *self.state.lock();
         if state != GameState::WaitingForPlayers && state != GameState::Betting {
             return Err(ReshuffleError::InvalidState);
         }
 
+        self.shuffle_new_shoe();
+        self.record(LoggedAction::Reshuffle);
+        Ok(())
+    }
+
+    /// Replaces the shoe with a freshly shuffled one and clears the discards.
+    fn shuffle_new_shoe(&self) {
         let mut decks = self.decks.lock();
         let mut rng = self.rng.lock();
 
-        *decks = Self::create_shoe(self.options.decks, &mut rng);
+        let options = self.options();
+        *decks = Self::create_shoe(options.decks, options.deck_composition, &mut rng);
+        self.discards.lock().clear();
+        self.low_shoe_notified.store(false, Ordering::SeqCst);
+        drop(rng);
+        drop(decks);
+        self.emit(GameEvent::ShoeReshuffled);
+    }
+
+    /// Returns a copy of the current game options.
+    pub fn options(&self) -> GameOptions {
+        self.options.lock().clone()
+    }
+
+    /// Sets the deck penetration used by reshuffle checks.
+    ///
+    /// The new value takes effect immediately: the next
+    /// [`check_and_reshuffle`](Self::check_and_reshuffle) compares the shoe
+    /// against it. Use 0 to disable reshuffling.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OptionsError::InvalidPenetration`] if `value` is not between
+    /// 0 and 1; the previous penetration is kept.
+    pub fn set_penetration(&self, value: f64) -> Result<(), OptionsError> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(OptionsError::InvalidPenetration);
+        }
+        self.options.lock().penetration = value;
+        self.record(LoggedAction::SetPenetration { penetration: value });
+        Ok(())
+    }
+
+    /// Changes the game options between rounds.
+    ///
+    /// `f` edits a copy of the current options, which replaces them once
+    /// validated. Every field may be changed while the game is in
+    /// `WaitingForPlayers` or `Betting`: payouts, rounding, and rule flags
+    /// apply from the next deal, and bets already placed are kept. Changing
+    /// `decks` or `deck_composition` replaces the shoe with a freshly
+    /// shuffled one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OptionsError::InvalidState`] during a round or when
+    /// `blackjack_switch` changes while bets are placed, or the error from
+    /// [`GameOptions::validate`] if the new options are invalid. On error the
+    /// options are unchanged.
+    pub fn update_options(&self, f: impl FnOnce(&mut GameOptions)) -> Result<(), OptionsError> {
+        let state = self.state.lock();
+        if *state != GameState::WaitingForPlayers && *state != GameState::Betting {
+            return Err(OptionsError::InvalidState);
+        }
+
+        let mut options = self.options();
+        let previous_shoe = (options.decks, options.deck_composition);
+        let previous_switch = options.blackjack_switch;
+        f(&mut options);
+        options.validate()?;
+
+        // Placed bets were staked for the previous number of hands
+        if options.blackjack_switch != previous_switch && !self.bets.lock().is_empty() {
+            return Err(OptionsError::InvalidState);
+        }
+
+        let shoe = (options.decks, options.deck_composition);
+        *self.options.lock() = options.clone();
+        if shoe != previous_shoe {
+            *self.decks.lock() = Self::create_shoe(shoe.0, shoe.1, &mut self.rng.lock());
+            self.discards.lock().clear();
+            self.low_shoe_notified.store(false, Ordering::SeqCst);
+        }
+        drop(state);
+        self.record(LoggedAction::SetOptions {
+            options: Box::new(options),
+        });
 
         Ok(())
     }
@@ -143,11 +429,12 @@ impl Game {
     /// Returns `true` if the remaining cards are below the penetration threshold.
     /// If penetration is 0, always returns `false`.
     pub fn needs_reshuffle(&self) -> bool {
-        if self.options.penetration == 0.0 {
+        let options = self.options();
+        if options.penetration == 0.0 {
             return false;
         }
 
-        let total_cards = self.options.decks as usize * DECK_SIZE;
+        let total_cards = options.decks as usize * options.deck_composition.deck_size();
         let remaining = self.cards_remaining();
         #[expect(
             clippy::cast_precision_loss,
@@ -155,7 +442,7 @@ impl Game {
         )]
         let used_ratio = 1.0 - (remaining as f64 / total_cards as f64);
 
-        used_ratio >= self.options.penetration
+        used_ratio >= options.penetration
     }
 
     /// Checks penetration and reshuffles if needed.
@@ -175,9 +462,163 @@ impl Game {
         }
     }
 
-    /// Draws a card from the shoe.
+    /// Registers a callback fired when the shoe crosses the penetration threshold.
+    ///
+    /// The callback fires at most once per shoe, from the draw that first makes
+    /// [`needs_reshuffle`](Self::needs_reshuffle) return `true`, and is re-armed
+    /// by [`reshuffle`](Self::reshuffle). Registering a new callback replaces the
+    /// previous one.
+    pub fn set_low_shoe_callback(&self, callback: impl FnMut() + Send + 'static) {
+        *self.low_shoe_callback.lock() = Some(Box::new(callback));
+    }
+
+    /// Draws a card for the current round, counting it as dealt.
     fn draw(&self) -> Option<Card> {
-        self.decks.lock().pop()
+        let card = self.draw_from_shoe()?;
+        self.cards_dealt.fetch_add(1, Ordering::SeqCst);
+        Some(card)
+    }
+
+    /// Puts back a card drawn by an action that then failed, so the next draw
+    /// deals it again.
+    fn undraw(&self, card: Card) {
+        if let Some(round_shoe) = self.round_shoe.lock().as_mut() {
+            round_shoe.push(card);
+        } else {
+            self.decks.lock().push(card);
+        }
+        self.cards_dealt.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Draws a card from the round shoe if one is in use, otherwise the shoe.
+    ///
+    /// With `reshuffle_on_empty`, an empty shoe is first refilled from the
+    /// discard pile.
+    fn draw_from_shoe(&self) -> Option<Card> {
+        if let Some(round_shoe) = self.round_shoe.lock().as_mut() {
+            return round_shoe.pop();
+        }
+
+        let mut decks = self.decks.lock();
+        if decks.is_empty() && self.options().reshuffle_on_empty {
+            // Shuffle the discards back in so the round can go on
+            let mut rng = self.rng.lock();
+            decks.append(&mut self.discards.lock());
+            decks.shuffle(&mut *rng);
+            drop(rng);
+            self.emit(GameEvent::ShoeReshuffled);
+        }
+        let card = decks.pop();
+        drop(decks);
+
+        if card.is_some() {
+            self.notify_if_shoe_low();
+        }
+        card
+    }
+
+    /// Draws a card from the shoe straight to the discard pile.
+    ///
+    /// This is a low-level primitive for scripting variants and simulations,
+    /// for example to burn a card. It is only available between rounds;
+    /// during a round it returns `None` without drawing.
+    ///
+    /// Returns `None` if the shoe is empty.
+    pub fn draw_card(&self) -> Option<Card> {
+        let state = *self.state.lock();
+        if state != GameState::WaitingForPlayers && state != GameState::Betting {
+            return None;
+        }
+
+        let card = self.draw_from_shoe()?;
+        self.discards.lock().push(card);
+        self.record(LoggedAction::DrawCard);
+        Some(card)
+    }
+
+    /// Removes one copy of `card` from the shoe and puts it on the discard
+    /// pile.
+    ///
+    /// Like [`draw_card`](Self::draw_card), this is for experiments that need
+    /// an exact shoe composition (see
+    /// [`remaining_composition`](Self::remaining_composition)) and is only
+    /// available between rounds. If the shoe holds several copies, the one
+    /// nearest the top is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a round is in progress (not in `WaitingForPlayers`
+    /// or Betting state) or the card is not in the shoe.
+    pub fn remove_card(&self, card: Card) -> Result<(), ShoeError> {
+        let state = *self.state.lock();
+        if state != GameState::WaitingForPlayers && state != GameState::Betting {
+            return Err(ShoeError::InvalidState);
+        }
+
+        let mut decks = self.decks.lock();
+        let index = decks
+            .iter()
+            .rposition(|&c| c == card)
+            .ok_or(ShoeError::CardNotFound)?;
+        let removed = decks.remove(index);
+        drop(decks);
+
+        self.discards.lock().push(removed);
+        self.record(LoggedAction::RemoveCard { card });
+        Ok(())
+    }
+
+    /// Returns how many cards have been drawn since the round was dealt.
+    ///
+    /// This covers the initial deal and every later draw by players and the
+    /// dealer. It is reset by the next deal, so after a round it still reports
+    /// that round's total. Cards burned with [`draw_card`](Self::draw_card)
+    /// are not counted.
+    pub fn cards_dealt_this_round(&self) -> usize {
+        self.cards_dealt.load(Ordering::SeqCst)
+    }
+
+    /// Returns the cards discarded since the last reshuffle, oldest first.
+    ///
+    /// This includes cards burned with [`draw_card`](Self::draw_card) and the
+    /// cards of every round cleared with [`clear_round`](Self::clear_round).
+    pub fn discards(&self) -> Vec<Card> {
+        self.discards.lock().clone()
+    }
+
+    /// Fires the low-shoe callback if the threshold was just crossed.
+    fn notify_if_shoe_low(&self) {
+        if self.low_shoe_notified.load(Ordering::SeqCst) || !self.needs_reshuffle() {
+            return;
+        }
+        if self.low_shoe_notified.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        // The deal holds the state lock, so it fires the callback once done
+        if self.dealing.load(Ordering::SeqCst) {
+            self.low_shoe_pending.store(true, Ordering::SeqCst);
+            return;
+        }
+        self.fire_low_shoe_callback();
+    }
+
+    /// Fires the low-shoe callback held back while the round was dealt.
+    fn fire_pending_low_shoe(&self) {
+        if self.low_shoe_pending.swap(false, Ordering::SeqCst) {
+            self.fire_low_shoe_callback();
+        }
+    }
+
+    fn fire_low_shoe_callback(&self) {
+        // Take the callback out so it runs without any game lock held.
+        let callback = self.low_shoe_callback.lock().take();
+        if let Some(mut callback) = callback {
+            callback();
+            let mut slot = self.low_shoe_callback.lock();
+            if slot.is_none() {
+                *slot = Some(callback);
+            }
+        }
     }
 
     fn current_hand_inactive(&self) -> bool {
@@ -198,13 +639,24 @@ impl Game {
         }
     }
 
+    /// Starts the player turns, moving straight on to the dealer's turn when
+    /// no hand is left to play (e.g. every hand was a natural).
+    fn begin_player_turns(&self, state: &mut GameState) {
+        self.advance_if_current_inactive();
+        self.set_state(state, GameState::PlayerTurn);
+        if self.all_players_done() {
+            self.set_state(state, GameState::DealerTurn);
+        }
+    }
+
     /// Joins the game with the specified money amount.
     ///
     /// Returns the assigned player ID.
-    pub fn join(&self, money: usize) -> u8 {
+    pub fn join(&self, money: Chips) -> u8 {
         let id = self.next_id.fetch_add(1, Ordering::SeqCst);
         self.players.lock().push(id);
         self.money.lock().insert(id, money);
+        self.record(LoggedAction::Join { money });
         id
     }
 
@@ -213,7 +665,9 @@ impl Game {
         self.players.lock().retain(|&id| id != player_id);
         self.money.lock().remove(&player_id);
         self.bets.lock().remove(&player_id);
+        self.side_bets.lock().remove(&player_id);
         self.hands.lock().remove(&player_id);
+        self.record(LoggedAction::Leave { player_id });
     }
 
     /// Returns the number of active players.
@@ -222,14 +676,105 @@ impl Game {
     }
 
     /// Returns the number of cards remaining in the shoe.
+    ///
+    /// While a round dealt with [`deal_from`](Self::deal_from) is in progress,
+    /// this counts the cards left in the supplied round shoe instead.
     pub fn cards_remaining(&self) -> usize {
+        if let Some(round_shoe) = self.round_shoe.lock().as_ref() {
+            return round_shoe.len();
+        }
         self.decks.lock().len()
     }
 
+    /// Returns how many cards of each rank remain in the shoe.
+    ///
+    /// Index `rank - 1` holds the count for that rank (index 0 is aces,
+    /// index 12 is kings). Like [`cards_remaining`](Self::cards_remaining),
+    /// this counts the round shoe while one is in use.
+    pub fn remaining_composition(&self) -> [usize; 13] {
+        let mut counts = [0; 13];
+        let round_shoe = self.round_shoe.lock();
+        let decks = self.decks.lock();
+        for card in round_shoe.as_deref().unwrap_or(&decks) {
+            if let Some(count) = (card.rank as usize)
+                .checked_sub(1)
+                .and_then(|index| counts.get_mut(index))
+            {
+                *count += 1;
+            }
+        }
+        drop(decks);
+        drop(round_shoe);
+        counts
+    }
+
+    /// Returns how many cards of each blackjack value remain in the shoe.
+    ///
+    /// Index `v` holds the count of cards worth `v`: aces are counted at
+    /// index 1, and tens and face cards together at index 10. Index 0 is
+    /// always zero.
+    pub fn remaining_by_value(&self) -> [usize; 11] {
+        let by_rank = self.remaining_composition();
+        let mut counts = [0; 11];
+        for (index, count) in by_rank.into_iter().enumerate() {
+            counts[(index + 1).min(10)] += count;
+        }
+        counts
+    }
+
     /// Starts the betting phase.
     pub fn start_betting(&self) {
+        self.set_state(&mut self.state.lock(), GameState::Betting);
+        self.record(LoggedAction::StartBetting);
+    }
+
+    /// Moves the game to another state.
+    ///
+    /// Only the edges allowed by
+    /// [`GameState::can_transition_to`] are accepted. This is a low-level
+    /// escape hatch: the regular game methods move between states on their
+    /// own.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateError::IllegalTransition`] if the game cannot move from
+    /// its current state to `to`; the state is left unchanged.
+    pub fn transition(&self, to: GameState) -> Result<(), StateError> {
         let mut state = self.state.lock();
-        *state = GameState::Betting;
+        if !state.can_transition_to(to) {
+            return Err(StateError::IllegalTransition { from: *state, to });
+        }
+        let from = *state;
+        *state = to;
+        drop(state);
+        self.emit(GameEvent::StateChanged { from, to });
+        self.record(LoggedAction::Transition { to });
+        Ok(())
+    }
+
+    /// Sets the state, checking in debug builds that the move is legal.
+    fn set_state(&self, state: &mut GameState, to: GameState) {
+        debug_assert!(
+            state.can_transition_to(to),
+            "illegal state transition from {state:?} to {to:?}"
+        );
+        let from = *state;
+        *state = to;
+        self.emit(GameEvent::StateChanged { from, to });
+    }
+
+    /// Turns the dealer's hole card face up, announcing it if it was face
+    /// down.
+    fn reveal_dealer_hole(&self) {
+        let mut dealer = self.dealer_hand.lock();
+        let hole = (!dealer.is_hole_revealed())
+            .then(|| dealer.cards().get(1).copied())
+            .flatten();
+        dealer.reveal_hole();
+        drop(dealer);
+        if let Some(card) = hole {
+            self.emit(GameEvent::DealerRevealedHole { card });
+        }
     }
 
     /// Returns the current game state.
@@ -242,6 +787,15 @@ impl Game {
         *self.current_turn.lock()
     }
 
+    /// Returns the IDs of the players dealt into the current round, in the
+    /// order they act.
+    ///
+    /// The order is set by [`deal`](Self::deal) and is empty before the
+    /// first deal and after [`clear_round`](Self::clear_round).
+    pub fn betting_order(&self) -> Vec<u8> {
+        self.betting_order.lock().clone()
+    }
+
     /// Returns the player ID whose turn it is.
     ///
     /// Returns `None` if there is no active turn (e.g., before dealing or after
@@ -252,16 +806,128 @@ impl Game {
         order.get(turn.player_index).copied()
     }
 
+    /// Returns the index of the player's hand that is waiting to act.
+    ///
+    /// Returns `None` outside of player turns, or if it is not this player's
+    /// turn (they are still waiting, or have finished all their hands).
+    pub fn pending_hand_index(&self, player_id: u8) -> Option<usize> {
+        if *self.state.lock() != GameState::PlayerTurn {
+            return None;
+        }
+
+        let turn = self.current_turn();
+        (self.current_player()? == player_id).then_some(turn.hand_index)
+    }
+
     /// Returns the current bet for the specified player.
-    pub fn get_bet(&self, player_id: u8) -> Option<usize> {
+    pub fn get_bet(&self, player_id: u8) -> Option<Chips> {
         self.bets.lock().get(&player_id).copied()
     }
 
+    /// Returns the amount paid to the player during the deal for a natural.
+    ///
+    /// Returns `None` unless `immediate_blackjack_payout` paid this player's
+    /// natural in the current round, or the player took
+    /// [even money](Self::take_even_money).
+    pub fn immediate_payout(&self, player_id: u8) -> Option<Chips> {
+        self.immediate_payouts.lock().get(&player_id).copied()
+    }
+
+    /// Returns the total the player has on the table this round.
+    ///
+    /// This sums the bets on every hand (including splits and doubles), the
+    /// insurance bet and the side bets still open. Before the deal it is the
+    /// placed bet and side bets. Surrendered hands and side bets decided by
+    /// the opening deal are not counted, since their stake has already been
+    /// settled.
+    pub fn total_at_risk(&self, player_id: u8) -> Chips {
+        let options = self.options();
+        let (dealt, hands_total) = self.hands.lock().get(&player_id).map_or_else(
+            || {
+                let bet = self.get_bet(player_id).unwrap_or_default();
+                (
+                    false,
+                    bet.saturating_mul(Self::hands_per_player(&options) as u64),
+                )
+            },
+            |hands| {
+                let total = hands
+                    .iter()
+                    .filter(|hand| hand.status() != HandStatus::Surrendered)
+                    .map(Hand::bet)
+                    .fold(Chips::ZERO, Chips::saturating_add);
+                (true, total)
+            },
+        );
+        let side_bets_total = self
+            .side_bets
+            .lock()
+            .get(&player_id)
+            .into_iter()
+            .flatten()
+            .filter(|&&(kind, _)| !dealt || options.side_bet(kind).settles_at_showdown())
+            .map(|&(_, bet)| bet)
+            .fold(Chips::ZERO, Chips::saturating_add);
+        hands_total
+            .saturating_add(self.get_insurance_bet(player_id).unwrap_or_default())
+            .saturating_add(side_bets_total)
+    }
+
     /// Returns the current money for the specified player.
-    pub fn get_money(&self, player_id: u8) -> Option<usize> {
+    pub fn get_money(&self, player_id: u8) -> Option<Chips> {
         self.money.lock().get(&player_id).copied()
     }
 
+    /// Applies a signed correction to a player's money and returns the new
+    /// balance.
+    ///
+    /// This is the sanctioned path for operator corrections such as a misdeal
+    /// refund or a dispute, rather than editing [`money`](Self::money)
+    /// directly. It is only available between rounds. A debit larger than the
+    /// balance is rejected rather than clamped, leaving the balance unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a round is in progress (not in `WaitingForPlayers`
+    /// or Betting state), the player cannot be found, or the new balance
+    /// would be negative or overflow.
+    pub fn adjust_funds(&self, player_id: u8, delta: i64) -> Result<Chips, FundsError> {
+        let state = *self.state.lock();
+        if state != GameState::WaitingForPlayers && state != GameState::Betting {
+            return Err(FundsError::InvalidState);
+        }
+
+        let mut money = self.money.lock();
+        let player_money = money
+            .get_mut(&player_id)
+            .ok_or(FundsError::PlayerNotFound)?;
+        *player_money = player_money.checked_add_signed(delta).ok_or(if delta < 0 {
+            FundsError::InsufficientFunds
+        } else {
+            FundsError::Overflow
+        })?;
+        let balance = *player_money;
+        drop(money);
+
+        self.record(LoggedAction::AdjustFunds { player_id, delta });
+        Ok(balance)
+    }
+
+    /// Returns every seated player with their money, richest first.
+    ///
+    /// Players with equal money are ordered by player ID.
+    pub fn standings(&self) -> Vec<(u8, Chips)> {
+        let players = self.players.lock().clone();
+        let money = self.money.lock();
+        let mut standings: Vec<(u8, Chips)> = players
+            .iter()
+            .map(|&id| (id, money.get(&id).copied().unwrap_or_default()))
+            .collect();
+        drop(money);
+        standings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        standings
+    }
+
     /// Returns the player's hands.
     ///
     /// Returns `None` if the player ID is not found.
@@ -269,26 +935,203 @@ impl Game {
         self.hands.lock().get(&player_id).cloned()
     }
 
+    /// Returns whether the specified hand is a natural (two-card 21, not from a split).
+    ///
+    /// Returns `false` if the player or hand is not found.
+    pub fn player_has_natural(&self, player_id: u8, hand_index: usize) -> bool {
+        self.hands
+            .lock()
+            .get(&player_id)
+            .and_then(|player_hands| player_hands.get(hand_index))
+            .is_some_and(Hand::is_natural)
+    }
+
+    /// Returns the players dealt a natural this round, in betting order.
+    ///
+    /// Available from the deal until the round is cleared; empty otherwise.
+    pub fn naturals_this_round(&self) -> Vec<u8> {
+        let order = self.betting_order.lock().clone();
+        let hands = self.hands.lock();
+        let naturals = order
+            .into_iter()
+            .filter(|id| {
+                hands
+                    .get(id)
+                    .is_some_and(|player_hands| player_hands.iter().any(Hand::is_natural))
+            })
+            .collect();
+        drop(hands);
+        naturals
+    }
+
+    /// Returns the players who still have a live stake, in betting order.
+    ///
+    /// A player is listed if at least one of their hands is active, standing
+    /// or a blackjack; players whose hands all busted or surrendered are left
+    /// out. This is the per-player form of the check that decides whether the
+    /// dealer plays out their hand.
+    pub fn players_with_live_hands(&self) -> Vec<u8> {
+        let order = self.betting_order.lock().clone();
+        let hands = self.hands.lock();
+        let live = order
+            .into_iter()
+            .filter(|id| {
+                hands.get(id).is_some_and(|player_hands| {
+                    player_hands.iter().any(|hand| {
+                        matches!(
+                            hand.status(),
+                            HandStatus::Active
+                                | HandStatus::Stand
+                                | HandStatus::Blackjack
+                                | HandStatus::Charlie
+                        )
+                    })
+                })
+            })
+            .collect();
+        drop(hands);
+        live
+    }
+
+    /// Returns whether the dealer has a blackjack that has been revealed.
+    ///
+    /// This is `true` once a peek (see `dealer_peek`) or insurance has shown
+    /// a dealer natural, or when the dealer's cards are face up. A dealer
+    /// blackjack still hidden under the hole card is not reported.
+    pub fn dealer_has_blackjack(&self) -> bool {
+        let dealer = self.dealer_hand.lock();
+        dealer.is_hole_revealed() && dealer.is_blackjack()
+    }
+
     /// Returns a clone of the dealer's hand.
     pub fn get_dealer_hand(&self) -> DealerHand {
         self.dealer_hand.lock().clone()
     }
 
+    /// Returns the dealer's final hand from the most recently cleared round.
+    ///
+    /// Returns `None` if no dealt round has been cleared yet.
+    pub fn last_dealer_hand(&self) -> Option<DealerHand> {
+        self.last_dealer_hand.lock().clone()
+    }
+
+    /// Returns a view of the table with all hidden information removed.
+    ///
+    /// The dealer's hole card appears as `None` until it is revealed.
+    pub fn spectator_view(&self) -> SpectatorView {
+        let state = self.state();
+        let dealer = DealerView::from(&*self.dealer_hand.lock());
+
+        let seated = self.players.lock().clone();
+        let hands = self.hands.lock();
+        let players = seated
+            .into_iter()
+            .map(|player_id| PlayerView {
+                player_id,
+                hands: hands.get(&player_id).cloned().unwrap_or_default(),
+            })
+            .collect();
+        drop(hands);
+
+        SpectatorView {
+            state,
+            dealer,
+            players,
+        }
+    }
+
     /// Clears all hands and bets (called at the end of a round).
     ///
     /// This also resets the turn position and returns the game to the
-    /// `WaitingForPlayers` state.
+    /// `WaitingForPlayers` state. The round's cards go to the discard pile,
+    /// unless the round was dealt with [`deal_from`](Self::deal_from). The
+    /// dealer's hand is kept and remains available from
+    /// [`last_dealer_hand`](Self::last_dealer_hand).
     pub fn clear_round(&self) {
+        self.reset_round();
+        self.record(LoggedAction::ClearRound);
+    }
+
+    /// Clears the round without recording the call.
+    fn reset_round(&self) {
+        let used_round_shoe = self.round_shoe.lock().take().is_some();
         self.bets.lock().clear();
-        self.hands.lock().clear();
-        self.dealer_hand.lock().clear();
+        let hands = core::mem::take(&mut *self.hands.lock());
+        let dealer = self.dealer_hand.lock().take();
+        if !used_round_shoe {
+            let mut discards = self.discards.lock();
+            for hand in hands.values().flatten() {
+                discards.extend_from_slice(hand.cards());
+            }
+            discards.extend_from_slice(dealer.cards());
+        }
+        if !dealer.is_empty() {
+            *self.last_dealer_hand.lock() = Some(dealer);
+        }
         self.betting_order.lock().clear();
         self.insurance_bets.lock().clear();
         self.insurance_decided.lock().clear();
+        self.immediate_payouts.lock().clear();
+        self.prepaid_hands.lock().clear();
+        self.even_money.lock().clear();
+        self.side_bets.lock().clear();
+        self.side_bet_results.lock().clear();
+        self.side_bet_cards.lock().clear();
+        self.settled_players.lock().clear();
         *self.current_turn.lock() = TurnPosition {
             player_index: 0,
             hand_index: 0,
         };
-        *self.state.lock() = GameState::WaitingForPlayers;
+        self.set_state(&mut self.state.lock(), GameState::WaitingForPlayers);
+    }
+
+    /// Clears the round like [`clear_round`](Self::clear_round), but places
+    /// each player's bet again for the next round.
+    ///
+    /// Money is taken at bet time and the showdown pays back the whole
+    /// return (stake included), so the kept bets are staked anew: each amount
+    /// is deducted from the player's money here, exactly as if
+    /// [`bet`](Self::bet) had been called. The amount kept is the original
+    /// bet, not any double or split made during the round, staked on both
+    /// hands under `blackjack_switch`. A bet is dropped if its player has
+    /// left, can no longer cover it, or it is outside the current table
+    /// limits.
+    ///
+    /// Call this after the showdown; a bet cancelled later with
+    /// [`cancel_bet`](Self::cancel_bet) is refunded as usual. Calling
+    /// [`bet`](Self::bet) for a player with a kept bet overwrites it without
+    /// refunding the kept stake, so cancel it first to change the amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BetError::InvalidState`] unless the game is in round-over
+    /// state, leaving the game untouched. A second call is therefore
+    /// rejected, since the first one leaves round-over state.
+    pub fn clear_round_keep_bets(&self) -> Result<(), BetError> {
+        if *self.state.lock() != GameState::RoundOver {
+            return Err(BetError::InvalidState);
+        }
+
+        let kept = self.bets.lock().clone();
+        let options = self.options();
+        self.reset_round();
+
+        let mut money = self.money.lock();
+        let mut bets = self.bets.lock();
+        for (player_id, amount) in kept {
+            let Ok(stake) = Self::check_bet(&options, amount) else {
+                continue;
+            };
+            if let Some(player_money) = money.get_mut(&player_id) {
+                if let Some(rest) = player_money.checked_sub(stake) {
+                    *player_money = rest;
+                    bets.insert(player_id, amount);
+                }
+            }
+        }
+        drop(bets);
+        drop(money);
+        self.record(LoggedAction::ClearRoundKeepBets);
+        Ok(())
     }
 }
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::error::{ActionError, BetError, RoundError};
+use crate::hand::Hand;
+use crate::result::{RoundResult, SimulationReport};
+
+use super::{Game, GameState};
+
+/// A playing decision returned by an automated strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerDecision {
+    /// Draw a card.
+    Hit,
+    /// Keep the current hand.
+    Stand,
+    /// Double the bet and draw one card.
+    Double,
+    /// Split a pair into two hands.
+    Split,
+    /// Forfeit half the bet.
+    Surrender,
+}
+
+impl Game {
+    /// Clears the current round and opens betting for the next one.
+    ///
+    /// This is [`clear_round`](Self::clear_round) followed by
+    /// [`start_betting`](Self::start_betting) for tables with fixed players.
+    /// The shoe is reshuffled in between if penetration has been reached.
+    /// Players and their money are kept; bets, hands, and insurance are
+    /// cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundError::NoPlayers`] if nobody is seated, leaving the game
+    /// untouched.
+    pub fn next_round(&self) -> Result<(), RoundError> {
+        if self.player_count() == 0 {
+            return Err(RoundError::NoPlayers);
+        }
+
+        self.clear_round();
+        self.check_and_reshuffle()?;
+        self.start_betting();
+        Ok(())
+    }
+
+    /// Plays a complete round with automated decisions.
+    ///
+    /// The shoe is reshuffled first if penetration has been reached. Each
+    /// `(player_id, amount)` pair in `bets` is placed, insurance is declined for
+    /// everyone, and `strategy` is asked for a decision on every hand that must
+    /// act. It receives the player ID, the hand, and the dealer's up card. The
+    /// dealer then plays, the round is settled, and the table is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the game is mid-round, or if any step of the round
+    /// fails (for example, the strategy picks an action that is not allowed).
+    /// On error the game is left as it was at the failing step.
+    pub fn play_round(
+        &self,
+        bets: &[(u8, Chips)],
+        mut strategy: impl FnMut(u8, &Hand, Option<Card>) -> PlayerDecision,
+    ) -> Result<RoundResult, RoundError> {
+        match self.state() {
+            GameState::WaitingForPlayers | GameState::Betting => {}
+            _ => return Err(BetError::InvalidState.into()),
+        }
+
+        self.check_and_reshuffle()?;
+        self.start_betting();
+        for &(player_id, amount) in bets {
+            self.bet(player_id, amount)?;
+        }
+        self.deal()?;
+
+        if self.state() == GameState::Insurance {
+            let order: Vec<u8> = self.betting_order.lock().clone();
+            for player_id in order {
+                self.decline_insurance(player_id)?;
+            }
+            self.finish_insurance()?;
+        }
+
+        while let (GameState::PlayerTurn, Some(player_id)) = (self.state(), self.current_player()) {
+            let hand_index = self.current_turn().hand_index;
+            let hand = self
+                .get_hands(player_id)
+                .and_then(|hands| hands.get(hand_index).cloned())
+                .ok_or(ActionError::HandNotFound)?;
+            let up_card = self.dealer_hand.lock().up_card().copied();
+
+            self.apply(player_id, strategy(player_id, &hand, up_card).into())?;
+        }
+
+        if self.state() == GameState::DealerTurn {
+            self.dealer_play()?;
+        }
+
+        let result = self.showdown()?;
+        self.clear_round();
+
+        Ok(result)
+    }
+
+    /// Plays `rounds` automated rounds with a flat bet and aggregates the results.
+    ///
+    /// Every round, each seated player who can place `bet` (including both
+    /// hands under `blackjack_switch`) bets it, and the round is played with
+    /// [`play_round`](Self::play_round), which reshuffles the shoe whenever
+    /// penetration is reached. The simulation stops early once no player can
+    /// cover the bet; the report covers the rounds completed up to that point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundError::Bet`] if `bet` is outside the table limits, or
+    /// the error of the first round that fails, in which case the game is
+    /// left as it was at the failing step.
+    pub fn simulate(
+        &self,
+        rounds: usize,
+        bet: Chips,
+        mut strategy: impl FnMut(u8, &Hand, Option<Card>) -> PlayerDecision,
+    ) -> Result<SimulationReport, RoundError> {
+        let stake = Self::check_bet(&self.options(), bet)?;
+        let mut report = SimulationReport::default();
+
+        for _ in 0..rounds {
+            let players = self.players.lock().clone();
+            let bets: Vec<(u8, Chips)> = players
+                .into_iter()
+                .filter(|&id| self.get_money(id).is_some_and(|money| money >= stake))
+                .map(|id| (id, bet))
+                .collect();
+            if bets.is_empty() {
+                break;
+            }
+
+            report.record(&self.play_round(&bets, &mut strategy)?);
+        }
+
+        Ok(report)
+    }
+}
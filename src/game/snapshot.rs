@@ -0,0 +1,162 @@
+use core::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::hand::{DealerHand, Hand};
+use crate::options::GameOptions;
+use crate::result::SideBetResult;
+use crate::side_bet::SideBetKind;
+use crate::sync::Mutex;
+
+use super::{Game, GameState, LoggedAction, TurnPosition};
+
+/// A complete copy of a game's state, taken with [`Game::snapshot`].
+///
+/// The snapshot holds the shoe and discards, the random number generator's
+/// position, the options, every player's money, bets, and hands, the dealer's
+/// hand, the turn position, the game state, and the action log. Restoring it
+/// with [`Game::restore`] gives a game that continues exactly where the
+/// original was, including the order of future shuffles.
+///
+/// The low-shoe callback and the event queue are not part of the snapshot;
+/// set them up again on the restored game. With the `serde` feature the snapshot can be serialized to
+/// persist a game across restarts.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+    decks: Vec<Card>,
+    round_shoe: Option<Vec<Card>>,
+    discards: Vec<Card>,
+    options: GameOptions,
+    state: GameState,
+    next_id: u8,
+    players: Vec<u8>,
+    money: BTreeMap<u8, Chips>,
+    bets: BTreeMap<u8, Chips>,
+    hands: BTreeMap<u8, Vec<Hand>>,
+    dealer_hand: DealerHand,
+    last_dealer_hand: Option<DealerHand>,
+    betting_order: Vec<u8>,
+    current_turn: TurnPosition,
+    insurance_bets: BTreeMap<u8, Chips>,
+    insurance_decided: Vec<u8>,
+    immediate_payouts: BTreeMap<u8, Chips>,
+    prepaid_hands: Vec<(u8, usize)>,
+    even_money: Vec<(u8, usize)>,
+    side_bets: BTreeMap<u8, Vec<(SideBetKind, Chips)>>,
+    side_bet_results: BTreeMap<u8, Vec<SideBetResult>>,
+    side_bet_cards: BTreeMap<u8, Vec<Card>>,
+    settled_players: Vec<u8>,
+    base_seed: u64,
+    rng_seed: [u8; 32],
+    rng_stream: u64,
+    rng_word_pos: u128,
+    low_shoe_notified: bool,
+    cards_dealt: usize,
+    action_log: Vec<LoggedAction>,
+}
+
+impl Game {
+    /// Captures the full game state, including mid-round state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, Game, GameOptions};
+    ///
+    /// let game = Game::new(GameOptions::default(), 42);
+    /// let player = game.join(Chips::new(100));
+    /// let snapshot = game.snapshot();
+    ///
+    /// let restored = Game::restore(snapshot);
+    /// assert_eq!(restored.get_money(player), Some(Chips::new(100)));
+    /// assert_eq!(restored.draw_card(), game.draw_card());
+    /// ```
+    pub fn snapshot(&self) -> GameSnapshot {
+        let rng = self.rng.lock().clone();
+        GameSnapshot {
+            decks: self.decks.lock().clone(),
+            round_shoe: self.round_shoe.lock().clone(),
+            discards: self.discards.lock().clone(),
+            options: self.options(),
+            state: *self.state.lock(),
+            next_id: self.next_id.load(Ordering::SeqCst),
+            players: self.players.lock().clone(),
+            money: self.money.lock().clone(),
+            bets: self.bets.lock().clone(),
+            hands: self.hands.lock().clone(),
+            dealer_hand: self.dealer_hand.lock().clone(),
+            last_dealer_hand: self.last_dealer_hand.lock().clone(),
+            betting_order: self.betting_order.lock().clone(),
+            current_turn: *self.current_turn.lock(),
+            insurance_bets: self.insurance_bets.lock().clone(),
+            insurance_decided: self.insurance_decided.lock().clone(),
+            immediate_payouts: self.immediate_payouts.lock().clone(),
+            prepaid_hands: self.prepaid_hands.lock().clone(),
+            even_money: self.even_money.lock().clone(),
+            side_bets: self.side_bets.lock().clone(),
+            side_bet_results: self.side_bet_results.lock().clone(),
+            side_bet_cards: self.side_bet_cards.lock().clone(),
+            settled_players: self.settled_players.lock().clone(),
+            base_seed: self.base_seed,
+            rng_seed: rng.get_seed(),
+            rng_stream: rng.get_stream(),
+            rng_word_pos: rng.get_word_pos(),
+            low_shoe_notified: self.low_shoe_notified.load(Ordering::SeqCst),
+            cards_dealt: self.cards_dealt.load(Ordering::SeqCst),
+            action_log: self.action_log(),
+        }
+    }
+
+    /// Creates a game from a snapshot taken with [`snapshot`](Self::snapshot).
+    ///
+    /// The restored game has no low-shoe callback and does not collect
+    /// events.
+    #[must_use]
+    pub fn restore(snapshot: GameSnapshot) -> Self {
+        let mut rng = ChaCha8Rng::from_seed(snapshot.rng_seed);
+        rng.set_stream(snapshot.rng_stream);
+        rng.set_word_pos(snapshot.rng_word_pos);
+
+        Self {
+            decks: Mutex::new(snapshot.decks),
+            round_shoe: Mutex::new(snapshot.round_shoe),
+            discards: Mutex::new(snapshot.discards),
+            options: Mutex::new(snapshot.options),
+            state: Mutex::new(snapshot.state),
+            next_id: AtomicU8::new(snapshot.next_id),
+            players: Mutex::new(snapshot.players),
+            money: Mutex::new(snapshot.money),
+            bets: Mutex::new(snapshot.bets),
+            hands: Mutex::new(snapshot.hands),
+            dealer_hand: Mutex::new(snapshot.dealer_hand),
+            last_dealer_hand: Mutex::new(snapshot.last_dealer_hand),
+            betting_order: Mutex::new(snapshot.betting_order),
+            current_turn: Mutex::new(snapshot.current_turn),
+            insurance_bets: Mutex::new(snapshot.insurance_bets),
+            insurance_decided: Mutex::new(snapshot.insurance_decided),
+            immediate_payouts: Mutex::new(snapshot.immediate_payouts),
+            prepaid_hands: Mutex::new(snapshot.prepaid_hands),
+            even_money: Mutex::new(snapshot.even_money),
+            side_bets: Mutex::new(snapshot.side_bets),
+            side_bet_results: Mutex::new(snapshot.side_bet_results),
+            side_bet_cards: Mutex::new(snapshot.side_bet_cards),
+            settled_players: Mutex::new(snapshot.settled_players),
+            base_seed: snapshot.base_seed,
+            rng: Mutex::new(rng),
+            low_shoe_callback: Mutex::new(None),
+            low_shoe_notified: AtomicBool::new(snapshot.low_shoe_notified),
+            dealing: AtomicBool::new(false),
+            low_shoe_pending: AtomicBool::new(false),
+            cards_dealt: AtomicUsize::new(snapshot.cards_dealt),
+            action_log: Mutex::new(snapshot.action_log),
+            events_enabled: AtomicBool::new(false),
+            events: Mutex::new(Vec::new()),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 /// Game state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameState {
     /// Waiting for players to join.
     WaitingForPlayers,
@@ -19,8 +20,52 @@ pub enum GameState {
     RoundOver,
 }
 
+impl GameState {
+    /// Returns whether the game may move from this state to `to`.
+    ///
+    /// The legal edges are:
+    ///
+    /// - any state to itself, and any state to `WaitingForPlayers` (clearing
+    ///   the table)
+    /// - `WaitingForPlayers` to `Betting`
+    /// - `Betting` to `Dealing`, and `Betting` or `Dealing` to `Insurance`,
+    ///   `PlayerTurn` or `RoundOver` once the cards are out
+    /// - `Insurance` to `PlayerTurn` or `RoundOver`
+    /// - `PlayerTurn` to `DealerTurn`
+    /// - `DealerTurn` to `RoundOver`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameState;
+    ///
+    /// assert!(GameState::Insurance.can_transition_to(GameState::RoundOver));
+    /// assert!(!GameState::RoundOver.can_transition_to(GameState::PlayerTurn));
+    /// ```
+    #[must_use]
+    pub const fn can_transition_to(self, to: Self) -> bool {
+        if self as u8 == to as u8 {
+            return true;
+        }
+        matches!(
+            (self, to),
+            (_, Self::WaitingForPlayers)
+                | (Self::WaitingForPlayers, Self::Betting)
+                | (Self::Betting, Self::Dealing)
+                | (
+                    Self::Betting | Self::Dealing,
+                    Self::Insurance | Self::PlayerTurn | Self::RoundOver
+                )
+                | (Self::Insurance, Self::PlayerTurn | Self::RoundOver)
+                | (Self::PlayerTurn, Self::DealerTurn)
+                | (Self::DealerTurn, Self::RoundOver)
+        )
+    }
+}
+
 /// Represents the current turn position.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TurnPosition {
     /// Index into the betting players list.
     pub player_index: usize,
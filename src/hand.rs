@@ -3,19 +3,13 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 use crate::card::Card;
+use crate::chips::Chips;
+use crate::options::BlackjackQualifier;
 
-const fn card_value(rank: u8) -> u8 {
-    match rank {
-        1 => 11,
-        2..=10 => rank,
-        11..=13 => 10,
-        _ => 0,
-    }
-}
-
-fn evaluate_cards(cards: &[Card]) -> (u8, bool) {
+pub(crate) fn evaluate_cards(cards: &[Card]) -> (u8, bool) {
     let mut value: u8 = 0;
     let mut aces: u8 = 0;
 
@@ -23,7 +17,7 @@ fn evaluate_cards(cards: &[Card]) -> (u8, bool) {
         if card.rank == 1 {
             aces += 1;
         }
-        value = value.saturating_add(card_value(card.rank));
+        value = value.saturating_add(card.value());
     }
 
     while value > 21 && aces > 0 {
@@ -37,6 +31,7 @@ fn evaluate_cards(cards: &[Card]) -> (u8, bool) {
 
 /// Hand status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandStatus {
     /// Hand is active and can take actions.
     Active,
@@ -48,41 +43,76 @@ pub enum HandStatus {
     Blackjack,
     /// Player has surrendered.
     Surrendered,
+    /// Hand reached the table's Charlie card count without busting.
+    Charlie,
 }
 
 /// A player's hand.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hand {
     /// Cards in the hand.
     cards: Vec<Card>,
     /// Current status of the hand.
     status: HandStatus,
     /// Bet amount for this hand.
-    bet: usize,
+    bet: Chips,
     /// Whether this hand is from a split.
     from_split: bool,
+    /// Whether the bet on this hand has been doubled.
+    doubled: bool,
+    /// Whether this hand's second card was switched with another hand.
+    switched: bool,
+    /// Which hands count as a blackjack.
+    qualifier: BlackjackQualifier,
 }
 
 impl Hand {
     /// Creates a new empty hand with the given bet.
     #[must_use]
-    pub const fn new(bet: usize) -> Self {
+    pub const fn new(bet: Chips) -> Self {
         Self {
             cards: Vec::new(),
             status: HandStatus::Active,
             bet,
             from_split: false,
+            doubled: false,
+            switched: false,
+            qualifier: BlackjackQualifier::TwoCardOnly,
         }
     }
 
+    /// Sets which hands count as a blackjack, [`BlackjackQualifier::TwoCardOnly`]
+    /// by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{BlackjackQualifier, Card, Chips, Hand, Suit};
+    ///
+    /// let mut hand = Hand::new(Chips::new(10)).with_blackjack_qualifier(BlackjackQualifier::AnyTwentyOne);
+    /// hand.add_card(Card::new(Suit::Spades, 7));
+    /// hand.add_card(Card::new(Suit::Hearts, 7));
+    /// hand.add_card(Card::new(Suit::Clubs, 7));
+    /// assert!(hand.is_blackjack());
+    /// ```
+    #[must_use]
+    pub const fn with_blackjack_qualifier(mut self, qualifier: BlackjackQualifier) -> Self {
+        self.qualifier = qualifier;
+        self
+    }
+
     /// Creates a new hand from a split with a single card.
     #[must_use]
-    pub fn from_split(card: Card, bet: usize) -> Self {
+    pub fn from_split(card: Card, bet: Chips) -> Self {
         Self {
             cards: alloc::vec![card],
             status: HandStatus::Active,
             bet,
             from_split: true,
+            doubled: false,
+            switched: false,
+            qualifier: BlackjackQualifier::TwoCardOnly,
         }
     }
 
@@ -96,10 +126,14 @@ impl Hand {
         if value > 21 {
             self.status = HandStatus::Bust;
         }
-        // Check for blackjack (only on initial deal, not from split)
-        else if self.cards.len() == 2 && value == 21 && !self.from_split {
+        // Check for blackjack under the hand's qualifier
+        else if self.qualifies_as_blackjack() {
             self.status = HandStatus::Blackjack;
         }
+        // A natural that does not qualify is an ordinary 21 and stands
+        else if self.is_natural() {
+            self.status = HandStatus::Stand;
+        }
     }
 
     /// Returns the cards in the hand.
@@ -121,13 +155,31 @@ impl Hand {
 
     /// Returns the bet amount for this hand.
     #[must_use]
-    pub const fn bet(&self) -> usize {
+    pub const fn bet(&self) -> Chips {
         self.bet
     }
 
     /// Doubles the bet amount.
-    pub const fn double_bet(&mut self) {
-        self.bet *= 2;
+    ///
+    /// A hand can only be doubled once: returns `true` if the bet was doubled,
+    /// or `false` (leaving the bet unchanged) if it had already been doubled
+    /// or the doubled bet would overflow.
+    pub const fn double_bet(&mut self) -> bool {
+        if self.doubled {
+            return false;
+        }
+        let Some(bet) = self.bet.checked_mul(2) else {
+            return false;
+        };
+        self.bet = bet;
+        self.doubled = true;
+        true
+    }
+
+    /// Returns whether the bet on this hand has been doubled.
+    #[must_use]
+    pub const fn is_doubled(&self) -> bool {
+        self.doubled
     }
 
     /// Returns whether this hand is from a split.
@@ -136,6 +188,13 @@ impl Hand {
         self.from_split
     }
 
+    /// Returns whether this hand's second card was switched with another
+    /// hand.
+    #[must_use]
+    pub const fn is_switched(&self) -> bool {
+        self.switched
+    }
+
     /// Calculates the value of the hand.
     ///
     /// Aces are counted as 11 if possible without busting, otherwise as 1.
@@ -150,10 +209,90 @@ impl Hand {
         evaluate_cards(&self.cards).1
     }
 
-    /// Returns whether the hand can be split.
+    /// Returns whether the hand is a blackjack: it has blackjack status and
+    /// its cards qualify under the hand's [`BlackjackQualifier`] (by default,
+    /// a natural; see [`is_natural`](Self::is_natural)).
+    ///
+    /// A hand given blackjack status by [`set_status`](Self::set_status)
+    /// without qualifying, such as a three-card 21 under the default
+    /// qualifier, is not a blackjack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Card, Chips, Hand, Suit};
+    ///
+    /// let mut hand = Hand::new(Chips::new(10));
+    /// hand.add_card(Card::new(Suit::Spades, 1));
+    /// hand.add_card(Card::new(Suit::Hearts, 12));
+    /// assert!(hand.is_blackjack());
+    /// ```
+    #[must_use]
+    pub fn is_blackjack(&self) -> bool {
+        self.status == HandStatus::Blackjack && self.qualifies_as_blackjack()
+    }
+
+    /// Returns whether the cards count as a blackjack under the qualifier.
+    fn qualifies_as_blackjack(&self) -> bool {
+        match self.qualifier {
+            BlackjackQualifier::TwoCardOnly => self.is_natural(),
+            BlackjackQualifier::SuitedAceKing => {
+                self.is_natural()
+                    && self.cards[0].suit == self.cards[1].suit
+                    && self.cards.iter().any(|card| card.rank == 13)
+            }
+            BlackjackQualifier::AnyTwentyOne => self.value() == 21,
+        }
+    }
+
+    /// Returns whether the hand is a natural: exactly two cards totalling 21,
+    /// not formed by a split or a switch.
+    #[must_use]
+    pub fn is_natural(&self) -> bool {
+        self.cards.len() == 2 && !self.from_split && !self.switched && self.value() == 21
+    }
+
+    /// Compares this hand's total against another total.
+    ///
+    /// A busted hand (over 21) ranks below every total, and any live total
+    /// ranks above a busted `other_value`. Two busted totals compare equal.
+    /// Naturals are not considered; see
+    /// [`compare_blackjack`](crate::compare::compare_blackjack).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use bjrs::{Card, Chips, Hand, Suit};
+    ///
+    /// let mut hand = Hand::new(Chips::new(10));
+    /// hand.add_card(Card::new(Suit::Hearts, 10));
+    /// hand.add_card(Card::new(Suit::Clubs, 9));
+    /// assert_eq!(hand.beats(18), Ordering::Greater);
+    /// assert_eq!(hand.beats(22), Ordering::Greater);
+    /// assert_eq!(hand.beats(20), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn beats(&self, other_value: u8) -> Ordering {
+        let value = self.value();
+        match (value > 21, other_value > 21) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => value.cmp(&other_value),
+        }
+    }
+
+    /// Returns whether the hand can be split: it holds two cards of the same
+    /// value, as [`split`](Self::split) requires.
+    ///
+    /// Two unlike ten-value cards, such as a jack and a king, count as a
+    /// pair here. Table rules, such as whether they may be split, are
+    /// checked by [`Game::split_deny_reason`](crate::Game::split_deny_reason).
     #[must_use]
     pub fn can_split(&self) -> bool {
-        self.cards.len() == 2 && self.cards[0].rank == self.cards[1].rank
+        self.cards.len() == 2 && self.cards[0].value() == self.cards[1].value()
     }
 
     /// Returns the number of cards in the hand.
@@ -168,6 +307,79 @@ impl Hand {
         self.cards.is_empty()
     }
 
+    /// Splits a pair into two hands.
+    ///
+    /// For a hand that [can be split](Self::can_split), removes the
+    /// second card and returns it as a new split hand carrying the same bet,
+    /// leaving this hand with the first card. Returns `None` and leaves the
+    /// hand unchanged otherwise. Table rules, such as whether unlike
+    /// ten-value cards may be split, are checked by
+    /// [`Game::split`](crate::Game::split).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Card, Chips, Hand, Suit};
+    ///
+    /// let mut hand = Hand::new(Chips::new(10));
+    /// hand.add_card(Card::new(Suit::Spades, 8));
+    /// hand.add_card(Card::new(Suit::Hearts, 8));
+    ///
+    /// let split = hand.split().unwrap();
+    /// assert_eq!(hand.cards(), [Card::new(Suit::Spades, 8)]);
+    /// assert_eq!(split.cards(), [Card::new(Suit::Hearts, 8)]);
+    /// assert!(split.is_from_split());
+    /// ```
+    pub fn split(&mut self) -> Option<Self> {
+        if !self.can_split() {
+            return None;
+        }
+        let card = self.cards.pop()?;
+        Some(Self::from_split(card, self.bet).with_blackjack_qualifier(self.qualifier))
+    }
+
+    /// Swaps the second cards of this hand and `other`, as in Blackjack
+    /// Switch.
+    ///
+    /// Both hands must hold exactly two cards and neither may have been
+    /// switched already; otherwise nothing changes and `false` is returned.
+    /// After the switch a two-card 21 is an ordinary 21 rather than a
+    /// natural, so both hands are active again unless the qualifier is
+    /// [`BlackjackQualifier::AnyTwentyOne`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Card, Chips, Hand, Suit};
+    ///
+    /// let mut first = Hand::new(Chips::new(10));
+    /// first.add_card(Card::new(Suit::Spades, 1));
+    /// first.add_card(Card::new(Suit::Hearts, 6));
+    /// let mut second = Hand::new(Chips::new(10));
+    /// second.add_card(Card::new(Suit::Clubs, 10));
+    /// second.add_card(Card::new(Suit::Diamonds, 13));
+    ///
+    /// assert!(first.switch_with(&mut second));
+    /// assert_eq!(first.value(), 21);
+    /// assert!(!first.is_blackjack());
+    /// assert_eq!(second.value(), 16);
+    /// ```
+    pub fn switch_with(&mut self, other: &mut Self) -> bool {
+        if self.cards.len() != 2 || other.cards.len() != 2 || self.switched || other.switched {
+            return false;
+        }
+        core::mem::swap(&mut self.cards[1], &mut other.cards[1]);
+        for hand in [&mut *self, other] {
+            hand.switched = true;
+            hand.status = if hand.qualifies_as_blackjack() {
+                HandStatus::Blackjack
+            } else {
+                HandStatus::Active
+            };
+        }
+        true
+    }
+
     /// Removes and returns the second card (for splitting).
     pub fn take_split_card(&mut self) -> Option<Card> {
         if self.cards.len() == 2 {
@@ -180,6 +392,7 @@ impl Hand {
 
 /// The dealer's hand.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DealerHand {
     /// Cards in the hand.
     cards: Vec<Card>,
@@ -197,6 +410,30 @@ impl DealerHand {
         }
     }
 
+    /// Creates a dealer hand holding the given cards.
+    ///
+    /// The first card is the up card.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Card, DealerHand, Suit};
+    ///
+    /// let dealer = DealerHand::from_cards(
+    ///     &[Card::new(Suit::Spades, 1), Card::new(Suit::Hearts, 13)],
+    ///     true,
+    /// );
+    /// assert!(dealer.is_blackjack());
+    /// assert!(dealer.has_ace_upcard());
+    /// ```
+    #[must_use]
+    pub fn from_cards(cards: &[Card], hole_revealed: bool) -> Self {
+        Self {
+            cards: cards.to_vec(),
+            hole_revealed,
+        }
+    }
+
     /// Adds a card to the hand.
     pub fn add_card(&mut self, card: Card) {
         self.cards.push(card);
@@ -214,6 +451,19 @@ impl DealerHand {
         self.cards.first()
     }
 
+    /// Returns whether the up card is an ace.
+    #[must_use]
+    pub fn has_ace_upcard(&self) -> bool {
+        self.up_card().is_some_and(|c| c.rank == 1)
+    }
+
+    /// Returns the value of the up card (aces count as 11), or 0 if the hand
+    /// is empty.
+    #[must_use]
+    pub fn upcard_value(&self) -> u8 {
+        self.up_card().map_or(0, Card::value)
+    }
+
     /// Returns whether the hole card is revealed.
     #[must_use]
     pub const fn is_hole_revealed(&self) -> bool {
@@ -231,7 +481,7 @@ impl DealerHand {
         if self.hole_revealed {
             self.value()
         } else {
-            self.cards.first().map_or(0, |c| card_value(c.rank))
+            self.upcard_value()
         }
     }
 
@@ -259,6 +509,16 @@ impl DealerHand {
         evaluate_cards(&self.cards).1
     }
 
+    /// Returns whether the dealer must draw under the standard drawing rule.
+    ///
+    /// The dealer draws below 17 and stands on hard 17 or more. Soft 17 is a
+    /// stand if `stand_on_soft_17` is true, otherwise a hit.
+    #[must_use]
+    pub fn should_hit(&self, stand_on_soft_17: bool) -> bool {
+        let (value, is_soft) = evaluate_cards(&self.cards);
+        value < 17 || (value == 17 && is_soft && !stand_on_soft_17)
+    }
+
     /// Returns the number of cards.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -271,6 +531,12 @@ impl DealerHand {
         self.cards.is_empty()
     }
 
+    /// Returns the current hand and leaves an empty one in its place.
+    #[must_use]
+    pub fn take(&mut self) -> Self {
+        core::mem::take(self)
+    }
+
     /// Clears the hand for a new round.
     pub fn clear(&mut self) {
         self.cards.clear();
@@ -22,18 +22,44 @@ compile_error!(
 
 extern crate alloc;
 
+pub mod bonus;
 pub mod card;
+pub mod chips;
+pub mod compare;
 pub mod error;
 pub mod game;
 pub mod hand;
 pub mod options;
+pub mod payout;
 pub mod result;
+pub mod side_bet;
 mod sync;
+pub mod tournament;
+pub mod view;
 
 // Re-export main types
-pub use card::{Card, DECK_SIZE, Suit};
-pub use error::{ActionError, BetError, DealError, InsuranceError, ReshuffleError, ShowdownError};
-pub use game::{Game, GameState, TurnPosition};
+pub use bonus::spanish_21_bonus;
+pub use card::{Card, Color, DECK_SIZE, SPANISH_DECK_SIZE, Suit, spanish_deck, standard_deck};
+pub use chips::Chips;
+pub use compare::{Tie, compare_blackjack};
+pub use error::{
+    ActionError, BetError, DealError, FundsError, InsuranceError, OptionsError, ReplayError,
+    ReshuffleError, RoundError, ShoeError, ShowdownError, StateError,
+};
+pub use game::{
+    Action, ActionSet, CardRecipient, Game, GameEvent, GameSnapshot, GameState, HitOutcome,
+    LoggedAction, PlayerDecision, SplitDenyReason, StandingHint, TurnPosition,
+};
 pub use hand::{DealerHand, Hand, HandStatus};
-pub use options::{DoubleOption, GameOptions, RoundingMode};
-pub use result::{HandOutcome, HandResult, PlayerResult, RoundResult};
+pub use options::{
+    BlackjackQualifier, DealOrder, DeckComposition, DoubleOption, GameOptions, RoundingMode,
+    SurrenderRule,
+};
+pub use payout::Payout;
+pub use result::{
+    HandOutcome, HandResult, LedgerEntry, LedgerSource, PlayerResult, RoundResult, SideBetResult,
+    SimulationReport,
+};
+pub use side_bet::{Buster, LuckyLadies, LuckyLucky, PairKind, PerfectPairs, SideBet, SideBetKind};
+pub use tournament::Tournament;
+pub use view::{DealerView, PlayerView, SpectatorView};
@@ -1,7 +1,17 @@
 //! Game configuration options.
 
+use alloc::vec::Vec;
+
+use crate::card::{Card, DECK_SIZE, SPANISH_DECK_SIZE, spanish_deck, standard_deck};
+use crate::chips::Chips;
+use crate::compare::Tie;
+use crate::error::OptionsError;
+use crate::payout::Payout;
+use crate::side_bet::{Buster, LuckyLadies, LuckyLucky, PerfectPairs, SideBet, SideBetKind};
+
 /// Conditions under which doubling down is allowed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum DoubleOption {
     /// Double down allowed on any hand.
@@ -17,8 +27,99 @@ pub enum DoubleOption {
     None,
 }
 
+/// Order in which the initial cards are dealt.
+///
+/// Every order deals the same cards to the same seats in total; only which
+/// physical card from the shoe each seat receives changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DealOrder {
+    /// One card to each player, the dealer's up card, a second card to each
+    /// player, then the dealer's hole card.
+    #[default]
+    Standard,
+    /// The dealer's up card, one card to each player, a second card to each
+    /// player, then the dealer's hole card.
+    DealerUpcardFirst,
+}
+
+/// Which player hands count as a blackjack and earn the blackjack payout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BlackjackQualifier {
+    /// Any two-card 21 not formed by a split (standard).
+    #[default]
+    TwoCardOnly,
+    /// Only a two-card ace and king of the same suit, not formed by a split.
+    ///
+    /// Other naturals are ordinary 21s: they stand automatically and win
+    /// even money, and against a dealer natural they are resolved like any
+    /// other tie.
+    SuitedAceKing,
+    /// Any hand totalling 21, whatever its card count.
+    AnyTwentyOne,
+}
+
+/// When players may surrender half their bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SurrenderRule {
+    /// Surrender is not offered.
+    None,
+    /// Surrender is offered once the dealer has checked for blackjack, on
+    /// the player's turn.
+    #[default]
+    Late,
+    /// Surrender is also offered before the dealer checks for blackjack.
+    ///
+    /// A dealer up card that would be checked (an ace with insurance
+    /// offered, or an ace or ten-value card with `dealer_peek`) opens the
+    /// insurance phase, and players may surrender until
+    /// [`Game::finish_insurance`](crate::Game::finish_insurance) resolves the
+    /// dealer's hand. Insurance bets are still only taken against an ace.
+    /// With `auto_resolve_insurance` there is no insurance phase, so only
+    /// late surrender is possible.
+    Early,
+}
+
+/// Which cards make up each deck in the shoe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DeckComposition {
+    /// Standard 52-card decks.
+    #[default]
+    Standard,
+    /// Spanish 48-card decks, without the ten-spot cards.
+    Spanish,
+}
+
+impl DeckComposition {
+    /// Returns one deck of this composition, unshuffled.
+    #[must_use]
+    pub fn deck(self) -> Vec<Card> {
+        match self {
+            Self::Standard => standard_deck().to_vec(),
+            Self::Spanish => spanish_deck().to_vec(),
+        }
+    }
+
+    /// Returns the number of cards in one deck of this composition.
+    #[must_use]
+    pub const fn deck_size(self) -> usize {
+        match self {
+            Self::Standard => DECK_SIZE,
+            Self::Spanish => SPANISH_DECK_SIZE,
+        }
+    }
+}
+
 /// Rounding mode for payouts.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RoundingMode {
     /// Round up.
     Up,
@@ -33,19 +134,24 @@ pub enum RoundingMode {
 /// Use the builder pattern to customize options:
 ///
 /// ```
-/// use bjrs::GameOptions;
+/// use bjrs::{GameOptions, Payout};
 ///
 /// let options = GameOptions::default()
 ///     .with_decks(6)
-///     .with_blackjack_pays(1.5)
+///     .with_blackjack_pays(Payout::new(3, 2))
 ///     .with_stand_on_soft_17(true);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameOptions {
     /// Number of decks.
     pub decks: u8,
-    /// Blackjack payout ratio (typically 1.5).
-    pub blackjack_pays: f64,
+    /// Which cards make up each deck.
+    pub deck_composition: DeckComposition,
+    /// Blackjack payout ratio (typically 3:2).
+    pub blackjack_pays: Payout,
+    /// Which player hands count as a blackjack.
+    pub blackjack_on: BlackjackQualifier,
     /// Whether dealer stands on soft 17.
     pub stand_on_soft_17: bool,
     /// Double down conditions.
@@ -58,40 +164,429 @@ pub struct GameOptions {
     pub split_aces_only_once: bool,
     /// Whether split aces receive only one card.
     pub split_aces_receive_one_card: bool,
-    /// Whether surrender is allowed.
-    pub surrender: bool,
+    /// Whether any two ten-value cards can be split (e.g. king-queen), rather
+    /// than only pairs of the same rank.
+    pub split_any_ten: bool,
+    /// When surrender is allowed.
+    pub surrender: SurrenderRule,
     /// Whether insurance is offered.
     pub insurance: bool,
+    /// Whether insurance is declined for everyone and resolved during the
+    /// deal, skipping the insurance state.
+    pub auto_resolve_insurance: bool,
+    /// Insurance payout ratio (typically 2:1).
+    pub insurance_pays: Payout,
     /// Rounding mode for blackjack payouts.
     pub rounding_blackjack: RoundingMode,
     /// Rounding mode for surrender payouts.
     pub rounding_surrender: RoundingMode,
+    /// Rounding mode for insurance: both the insurance bet, half of an odd
+    /// wager, and its payout under a custom `insurance_pays`.
+    pub rounding_insurance: RoundingMode,
+    /// Rounding mode for any other fractional payout.
+    ///
+    /// Payouts with a dedicated rounding field use that field; every other
+    /// payout that can produce a fraction (such as side bets) uses this
+    /// mode.
+    pub rounding_default: RoundingMode,
+    /// Whether every seated player must bet before the round can be dealt.
+    pub require_all_bet: bool,
+    /// Order in which the initial cards are dealt.
+    pub deal_order: DealOrder,
+    /// Number of cards each player is dealt at the start of a round.
+    ///
+    /// The dealer takes the up card with the first round of cards and the
+    /// hole card with the second. Only two-card hands can be naturals.
+    pub initial_cards: u8,
+    /// Extra cards that must remain in the shoe beyond the initial deal for a
+    /// round to start, so it is not left without cards mid-hand.
+    pub reserve_cards: usize,
+    /// Whether both dealer cards are dealt face up (Double Exposure).
+    ///
+    /// The hole card is face up from the moment it is dealt. Insurance is not
+    /// offered, a player blackjack pays even money whatever `blackjack_pays`
+    /// says, and the dealer wins ties, except that a player blackjack still
+    /// wins.
+    pub double_exposure: bool,
+    /// How equal totals are settled at showdown.
+    ///
+    /// [`Tie::Dealer`] makes the dealer win pushes. Double Exposure tables
+    /// always use [`Tie::Dealer`].
+    pub ties: Tie,
+    /// Whether a dealer bust on exactly 22 pushes every hand still standing
+    /// instead of paying it (Blackjack Switch style).
+    ///
+    /// Player naturals are still paid.
+    pub push_on_dealer_22: bool,
+    /// Whether each player plays two hands with equal bets and may switch
+    /// their second cards before acting (Blackjack Switch).
+    ///
+    /// A bet of `amount` places `amount` on each hand, so `2 * amount` is
+    /// taken from the player. See [`Game::switch`](crate::Game::switch).
+    pub blackjack_switch: bool,
+    /// Whether Spanish 21 bonuses are paid on player 21s.
+    ///
+    /// See [`spanish_21_bonus`](crate::bonus::spanish_21_bonus) for the hands
+    /// and ratios. A bonus hand wins even against a dealer 21, but not
+    /// against a dealer blackjack, and settles as
+    /// [`HandOutcome::Bonus`](crate::HandOutcome::Bonus) with the winnings
+    /// rounded by `rounding_default`. Doubled hands are paid as usual.
+    pub spanish_bonuses: bool,
+    /// Whether the table plays European no-hole-card (ENHC) rules.
+    ///
+    /// The dealer takes only the up card during the deal and draws the second
+    /// card when the dealer plays. If no player hand is left for the dealer to
+    /// play against, the second card is never drawn.
+    pub no_hole_card: bool,
+    /// Whether the dealer peeks at the hole card during the deal when the up
+    /// card is an ace or a ten-value card.
+    ///
+    /// If the dealer has blackjack, the hole card is revealed and the round
+    /// ends before players act: player naturals push and every other hand
+    /// loses. Otherwise the hole card stays face down and play continues.
+    /// With an ace up and insurance offered, the peek happens once insurance
+    /// is resolved, as without this option.
+    pub dealer_peek: bool,
+    /// Whether player naturals are paid during the deal when the dealer's up
+    /// card cannot make blackjack (anything but an ace or a ten-value card).
+    ///
+    /// The payout is still reported in the round's showdown results.
+    pub immediate_blackjack_payout: bool,
+    /// Number of cards after which a player hand stands automatically,
+    /// whatever its total.
+    pub max_cards_per_hand: u8,
+    /// Number of cards at which a hand that has not busted wins
+    /// automatically (a "Charlie"), or `None` for no Charlie rule.
+    ///
+    /// The hand stops drawing at once and is paid even money unless the
+    /// dealer has blackjack.
+    pub charlie: Option<u8>,
+    /// Whether the discard pile is shuffled back into the shoe when the shoe
+    /// runs out mid-round, instead of the draw failing.
+    ///
+    /// Cards seen earlier in the shoe can then come out again, so card
+    /// counting over a single shoe no longer holds.
+    pub reshuffle_on_empty: bool,
     /// Deck penetration (fraction of deck played before reshuffle).
     /// 0 to disable reshuffling.
     pub penetration: f64,
+    /// Smallest bet accepted by [`Game::bet`](crate::Game::bet).
+    pub min_bet: Chips,
+    /// Largest bet accepted by [`Game::bet`](crate::Game::bet).
+    pub max_bet: Chips,
+    /// Bets must be a multiple of this amount.
+    pub bet_increment: Chips,
+    /// Pay table for [`SideBetKind::PerfectPairs`].
+    pub perfect_pairs: PerfectPairs,
+    /// Pay table for [`SideBetKind::LuckyLadies`].
+    pub lucky_ladies: LuckyLadies,
+    /// Pay table for [`SideBetKind::LuckyLucky`].
+    pub lucky_lucky: LuckyLucky,
+    /// Pay ladder for [`SideBetKind::Buster`].
+    pub buster: Buster,
 }
 
 impl Default for GameOptions {
     fn default() -> Self {
         Self {
             decks: 2,
-            blackjack_pays: 1.5,
+            deck_composition: DeckComposition::Standard,
+            blackjack_pays: Payout::new(3, 2),
+            blackjack_on: BlackjackQualifier::TwoCardOnly,
             stand_on_soft_17: true,
             double: DoubleOption::Any,
             split: 3,
             double_after_split: true,
             split_aces_only_once: true,
             split_aces_receive_one_card: true,
-            surrender: true,
+            split_any_ten: false,
+            surrender: SurrenderRule::Late,
             insurance: true,
+            auto_resolve_insurance: false,
+            insurance_pays: Payout::new(2, 1),
             rounding_blackjack: RoundingMode::Down,
             rounding_surrender: RoundingMode::Nearest,
+            rounding_insurance: RoundingMode::Down,
+            rounding_default: RoundingMode::Down,
+            require_all_bet: false,
+            deal_order: DealOrder::Standard,
+            initial_cards: 2,
+            reserve_cards: 0,
+            double_exposure: false,
+            ties: Tie::Push,
+            push_on_dealer_22: false,
+            blackjack_switch: false,
+            spanish_bonuses: false,
+            no_hole_card: false,
+            dealer_peek: false,
+            immediate_blackjack_payout: false,
+            max_cards_per_hand: 11,
+            charlie: None,
+            reshuffle_on_empty: false,
             penetration: 0.75,
+            min_bet: Chips::new(1),
+            max_bet: Chips::MAX,
+            bet_increment: Chips::new(1),
+            perfect_pairs: PerfectPairs::default(),
+            lucky_ladies: LuckyLadies::default(),
+            lucky_lucky: LuckyLucky::default(),
+            buster: Buster::default(),
         }
     }
 }
 
 impl GameOptions {
+    /// Checks that the options describe a playable game.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OptionsError::NoDecks`] if `decks` is 0,
+    /// [`OptionsError::InvalidPayout`] if `blackjack_pays`, `insurance_pays`
+    /// or a side bet pay table ratio has a zero denominator, [`OptionsError::InvalidPenetration`] if
+    /// `penetration` is not between 0 and 1,
+    /// [`OptionsError::InvalidInitialCards`] if `initial_cards` is 0,
+    /// [`OptionsError::InvalidBetLimits`] if `bet_increment` is 0 or
+    /// `min_bet` is greater than `max_bet`,
+    /// [`OptionsError::InvalidCharlie`] if `charlie` is fewer than 3 cards, or
+    /// [`OptionsError::InvalidMaxCards`] if `max_cards_per_hand` is fewer than
+    /// `initial_cards`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, OptionsError};
+    ///
+    /// assert_eq!(GameOptions::default().validate(), Ok(()));
+    /// assert_eq!(
+    ///     GameOptions::default().with_decks(0).validate(),
+    ///     Err(OptionsError::NoDecks)
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), OptionsError> {
+        if self.decks == 0 {
+            return Err(OptionsError::NoDecks);
+        }
+        if !self.blackjack_pays.is_valid() || !self.insurance_pays.is_valid() {
+            return Err(OptionsError::InvalidPayout);
+        }
+        let side_bet_ratios = [
+            self.perfect_pairs.mixed,
+            self.perfect_pairs.colored,
+            self.perfect_pairs.perfect,
+            self.lucky_ladies.any_twenty,
+            self.lucky_ladies.suited_twenty,
+            self.lucky_ladies.matched_twenty,
+            self.lucky_ladies.queen_of_hearts_pair,
+            self.lucky_ladies.queen_of_hearts_pair_with_dealer_blackjack,
+            self.lucky_lucky.nineteen_or_twenty,
+            self.lucky_lucky.twenty_one,
+            self.lucky_lucky.suited_twenty_one,
+            self.lucky_lucky.six_seven_eight,
+            self.lucky_lucky.seven_seven_seven,
+            self.lucky_lucky.suited_six_seven_eight,
+            self.lucky_lucky.suited_seven_seven_seven,
+            self.buster.three_cards,
+            self.buster.four_cards,
+            self.buster.five_cards,
+            self.buster.six_cards,
+            self.buster.seven_cards,
+            self.buster.eight_or_more_cards,
+        ];
+        if !side_bet_ratios.into_iter().all(Payout::is_valid) {
+            return Err(OptionsError::InvalidPayout);
+        }
+        if !(0.0..=1.0).contains(&self.penetration) {
+            return Err(OptionsError::InvalidPenetration);
+        }
+        if self.initial_cards == 0 {
+            return Err(OptionsError::InvalidInitialCards);
+        }
+        if self.bet_increment.is_zero() || self.min_bet > self.max_bet {
+            return Err(OptionsError::InvalidBetLimits);
+        }
+        if self.charlie.is_some_and(|cards| cards < 3) {
+            return Err(OptionsError::InvalidCharlie);
+        }
+        if self.max_cards_per_hand < self.initial_cards {
+            return Err(OptionsError::InvalidMaxCards);
+        }
+        Ok(())
+    }
+
+    /// Las Vegas Strip rules.
+    ///
+    /// Six decks, dealer stands on soft 17 and peeks for blackjack, double on
+    /// any two cards, double after split, resplit to four hands, one card to
+    /// split aces, late surrender, and blackjack pays 3:2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::vegas_strip();
+    /// assert_eq!(options.decks, 6);
+    /// assert!(options.stand_on_soft_17);
+    /// ```
+    #[must_use]
+    pub fn vegas_strip() -> Self {
+        Self::default()
+            .with_decks(6)
+            .with_stand_on_soft_17(true)
+            .with_double(DoubleOption::Any)
+            .with_double_after_split(true)
+            .with_split(3)
+            .with_split_aces_only_once(true)
+            .with_split_aces_receive_one_card(true)
+            .with_surrender(SurrenderRule::Late)
+            .with_dealer_peek(true)
+            .with_blackjack_pays(Payout::new(3, 2))
+    }
+
+    /// Atlantic City rules.
+    ///
+    /// Eight decks, dealer stands on soft 17 and peeks for blackjack, double
+    /// on any two cards, double after split, resplit to four hands, one card
+    /// to split aces, late surrender, and blackjack pays 3:2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, SurrenderRule};
+    ///
+    /// let options = GameOptions::atlantic_city();
+    /// assert_eq!(options.decks, 8);
+    /// assert_eq!(options.surrender, SurrenderRule::Late);
+    /// ```
+    #[must_use]
+    pub fn atlantic_city() -> Self {
+        Self::default()
+            .with_decks(8)
+            .with_stand_on_soft_17(true)
+            .with_double(DoubleOption::Any)
+            .with_double_after_split(true)
+            .with_split(3)
+            .with_split_aces_only_once(true)
+            .with_split_aces_receive_one_card(true)
+            .with_surrender(SurrenderRule::Late)
+            .with_dealer_peek(true)
+            .with_blackjack_pays(Payout::new(3, 2))
+    }
+
+    /// Single-deck rules with the dealer hitting soft 17.
+    ///
+    /// One deck, dealer hits soft 17 and peeks for blackjack, double on any
+    /// two cards, no double after split, one split only, one card to split
+    /// aces, no surrender, and blackjack pays 3:2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::single_deck_h17();
+    /// assert_eq!(options.decks, 1);
+    /// assert!(!options.stand_on_soft_17);
+    /// ```
+    #[must_use]
+    pub fn single_deck_h17() -> Self {
+        Self::default()
+            .with_decks(1)
+            .with_stand_on_soft_17(false)
+            .with_double(DoubleOption::Any)
+            .with_double_after_split(false)
+            .with_split(1)
+            .with_split_aces_only_once(true)
+            .with_split_aces_receive_one_card(true)
+            .with_surrender(SurrenderRule::None)
+            .with_dealer_peek(true)
+            .with_blackjack_pays(Payout::new(3, 2))
+    }
+
+    /// Single-deck rules with the dealer standing on soft 17.
+    ///
+    /// One deck, dealer stands on soft 17 and peeks for blackjack, double on
+    /// any two cards, no double after split, one split only, one card to
+    /// split aces, no surrender, and blackjack pays 3:2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::single_deck();
+    /// assert_eq!(options.decks, 1);
+    /// assert!(options.stand_on_soft_17);
+    /// ```
+    #[must_use]
+    pub fn single_deck() -> Self {
+        Self::single_deck_h17().with_stand_on_soft_17(true)
+    }
+
+    /// European rules.
+    ///
+    /// Six decks with no hole card: the dealer takes a second card only when
+    /// playing, so a dealer blackjack also beats doubled and split hands.
+    /// Dealer stands on soft 17, double on 9 through 11 only, double after
+    /// split, one split only, one card to split aces, no surrender, and
+    /// blackjack pays 3:2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{DoubleOption, GameOptions};
+    ///
+    /// let options = GameOptions::european();
+    /// assert!(options.no_hole_card);
+    /// assert_eq!(options.double, DoubleOption::NineThrough11);
+    /// ```
+    #[must_use]
+    pub fn european() -> Self {
+        Self::default()
+            .with_decks(6)
+            .with_no_hole_card(true)
+            .with_stand_on_soft_17(true)
+            .with_double(DoubleOption::NineThrough11)
+            .with_double_after_split(true)
+            .with_split(1)
+            .with_split_aces_only_once(true)
+            .with_split_aces_receive_one_card(true)
+            .with_surrender(SurrenderRule::None)
+            .with_dealer_peek(false)
+            .with_blackjack_pays(Payout::new(3, 2))
+    }
+
+    /// Spanish 21 rules.
+    ///
+    /// Six Spanish decks (no tens), dealer hits soft 17 and peeks for
+    /// blackjack, double on any two cards, double after split, resplit to four
+    /// hands, late surrender, blackjack pays 3:2, and Spanish 21 bonuses on
+    /// five-or-more-card 21s, 6-7-8 and 7-7-7.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{DeckComposition, GameOptions};
+    ///
+    /// let options = GameOptions::spanish_21();
+    /// assert_eq!(options.deck_composition, DeckComposition::Spanish);
+    /// assert!(options.spanish_bonuses);
+    /// ```
+    #[must_use]
+    pub fn spanish_21() -> Self {
+        Self::default()
+            .with_decks(6)
+            .with_deck_composition(DeckComposition::Spanish)
+            .with_stand_on_soft_17(false)
+            .with_double(DoubleOption::Any)
+            .with_double_after_split(true)
+            .with_split(3)
+            .with_surrender(SurrenderRule::Late)
+            .with_dealer_peek(true)
+            .with_blackjack_pays(Payout::new(3, 2))
+            .with_spanish_bonuses(true)
+    }
+
     /// Sets the number of decks.
     ///
     /// # Example
@@ -108,22 +603,54 @@ impl GameOptions {
         self
     }
 
+    /// Sets which cards make up each deck.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{DeckComposition, GameOptions};
+    ///
+    /// let options = GameOptions::default().with_deck_composition(DeckComposition::Spanish);
+    /// assert_eq!(options.deck_composition.deck_size(), 48);
+    /// ```
+    #[must_use]
+    pub const fn with_deck_composition(mut self, composition: DeckComposition) -> Self {
+        self.deck_composition = composition;
+        self
+    }
+
     /// Sets the blackjack payout ratio.
     ///
     /// # Example
     ///
     /// ```
-    /// use bjrs::GameOptions;
+    /// use bjrs::{GameOptions, Payout};
     ///
-    /// let options = GameOptions::default().with_blackjack_pays(1.2);
-    /// assert_eq!(options.blackjack_pays, 1.2);
+    /// let options = GameOptions::default().with_blackjack_pays(Payout::new(6, 5));
+    /// assert_eq!(options.blackjack_pays, Payout::new(6, 5));
     /// ```
     #[must_use]
-    pub const fn with_blackjack_pays(mut self, ratio: f64) -> Self {
+    pub const fn with_blackjack_pays(mut self, ratio: Payout) -> Self {
         self.blackjack_pays = ratio;
         self
     }
 
+    /// Sets which player hands count as a blackjack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{BlackjackQualifier, GameOptions};
+    ///
+    /// let options = GameOptions::default().with_blackjack_on(BlackjackQualifier::AnyTwentyOne);
+    /// assert_eq!(options.blackjack_on, BlackjackQualifier::AnyTwentyOne);
+    /// ```
+    #[must_use]
+    pub const fn with_blackjack_on(mut self, qualifier: BlackjackQualifier) -> Self {
+        self.blackjack_on = qualifier;
+        self
+    }
+
     /// Sets whether dealer stands on soft 17.
     ///
     /// # Example
@@ -220,19 +747,35 @@ impl GameOptions {
         self
     }
 
-    /// Sets whether surrender is allowed.
+    /// Sets whether any two ten-value cards can be split.
     ///
     /// # Example
     ///
     /// ```
     /// use bjrs::GameOptions;
     ///
-    /// let options = GameOptions::default().with_surrender(false);
-    /// assert_eq!(options.surrender, false);
+    /// let options = GameOptions::default().with_split_any_ten(true);
+    /// assert_eq!(options.split_any_ten, true);
     /// ```
     #[must_use]
-    pub const fn with_surrender(mut self, allowed: bool) -> Self {
-        self.surrender = allowed;
+    pub const fn with_split_any_ten(mut self, any_ten: bool) -> Self {
+        self.split_any_ten = any_ten;
+        self
+    }
+
+    /// Sets when surrender is allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, SurrenderRule};
+    ///
+    /// let options = GameOptions::default().with_surrender(SurrenderRule::None);
+    /// assert_eq!(options.surrender, SurrenderRule::None);
+    /// ```
+    #[must_use]
+    pub const fn with_surrender(mut self, rule: SurrenderRule) -> Self {
+        self.surrender = rule;
         self
     }
 
@@ -252,6 +795,39 @@ impl GameOptions {
         self
     }
 
+    /// Sets whether insurance is declined for everyone and resolved during
+    /// the deal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_auto_resolve_insurance(true);
+    /// assert_eq!(options.auto_resolve_insurance, true);
+    /// ```
+    #[must_use]
+    pub const fn with_auto_resolve_insurance(mut self, auto_resolve: bool) -> Self {
+        self.auto_resolve_insurance = auto_resolve;
+        self
+    }
+
+    /// Sets the insurance payout ratio.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, Payout};
+    ///
+    /// let options = GameOptions::default().with_insurance_pays(Payout::new(3, 2));
+    /// assert_eq!(options.insurance_pays, Payout::new(3, 2));
+    /// ```
+    #[must_use]
+    pub const fn with_insurance_pays(mut self, ratio: Payout) -> Self {
+        self.insurance_pays = ratio;
+        self
+    }
+
     /// Sets the rounding mode for blackjack payouts.
     ///
     /// # Example
@@ -284,6 +860,281 @@ impl GameOptions {
         self
     }
 
+    /// Sets the rounding mode for insurance bets and payouts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, RoundingMode};
+    ///
+    /// let options = GameOptions::default().with_rounding_insurance(RoundingMode::Up);
+    /// assert_eq!(options.rounding_insurance, RoundingMode::Up);
+    /// ```
+    #[must_use]
+    pub const fn with_rounding_insurance(mut self, mode: RoundingMode) -> Self {
+        self.rounding_insurance = mode;
+        self
+    }
+
+    /// Sets the rounding mode for fractional payouts without a dedicated field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, RoundingMode};
+    ///
+    /// let options = GameOptions::default().with_rounding_default(RoundingMode::Up);
+    /// assert_eq!(options.rounding_default, RoundingMode::Up);
+    /// ```
+    #[must_use]
+    pub const fn with_rounding_default(mut self, mode: RoundingMode) -> Self {
+        self.rounding_default = mode;
+        self
+    }
+
+    /// Sets whether every seated player must bet before dealing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_require_all_bet(true);
+    /// assert_eq!(options.require_all_bet, true);
+    /// ```
+    #[must_use]
+    pub const fn with_require_all_bet(mut self, required: bool) -> Self {
+        self.require_all_bet = required;
+        self
+    }
+
+    /// Sets the order in which the initial cards are dealt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{DealOrder, GameOptions};
+    ///
+    /// let options = GameOptions::default().with_deal_order(DealOrder::DealerUpcardFirst);
+    /// assert_eq!(options.deal_order, DealOrder::DealerUpcardFirst);
+    /// ```
+    #[must_use]
+    pub const fn with_deal_order(mut self, order: DealOrder) -> Self {
+        self.deal_order = order;
+        self
+    }
+
+    /// Sets the number of cards each player is dealt at the start of a round.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_initial_cards(3);
+    /// assert_eq!(options.initial_cards, 3);
+    /// ```
+    #[must_use]
+    pub const fn with_initial_cards(mut self, cards: u8) -> Self {
+        self.initial_cards = cards;
+        self
+    }
+
+    /// Sets how many cards beyond the initial deal must remain to start a round.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_reserve_cards(10);
+    /// assert_eq!(options.reserve_cards, 10);
+    /// ```
+    #[must_use]
+    pub const fn with_reserve_cards(mut self, reserve: usize) -> Self {
+        self.reserve_cards = reserve;
+        self
+    }
+
+    /// Sets whether both dealer cards are dealt face up (Double Exposure).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_double_exposure(true);
+    /// assert!(options.double_exposure);
+    /// ```
+    #[must_use]
+    pub const fn with_double_exposure(mut self, enabled: bool) -> Self {
+        self.double_exposure = enabled;
+        self
+    }
+
+    /// Sets how equal totals are settled at showdown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, Tie};
+    ///
+    /// let options = GameOptions::default().with_ties(Tie::Dealer);
+    /// assert_eq!(options.ties, Tie::Dealer);
+    /// ```
+    #[must_use]
+    pub const fn with_ties(mut self, ties: Tie) -> Self {
+        self.ties = ties;
+        self
+    }
+
+    /// Sets whether a dealer 22 pushes the hands still standing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_push_on_dealer_22(true);
+    /// assert!(options.push_on_dealer_22);
+    /// ```
+    #[must_use]
+    pub const fn with_push_on_dealer_22(mut self, enabled: bool) -> Self {
+        self.push_on_dealer_22 = enabled;
+        self
+    }
+
+    /// Sets whether players play two hands each and may switch their second
+    /// cards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_blackjack_switch(true);
+    /// assert!(options.blackjack_switch);
+    /// ```
+    #[must_use]
+    pub const fn with_blackjack_switch(mut self, enabled: bool) -> Self {
+        self.blackjack_switch = enabled;
+        self
+    }
+
+    /// Sets whether Spanish 21 bonuses are paid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_spanish_bonuses(true);
+    /// assert!(options.spanish_bonuses);
+    /// ```
+    #[must_use]
+    pub const fn with_spanish_bonuses(mut self, enabled: bool) -> Self {
+        self.spanish_bonuses = enabled;
+        self
+    }
+
+    /// Sets whether the table plays European no-hole-card (ENHC) rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_no_hole_card(true);
+    /// assert!(options.no_hole_card);
+    /// ```
+    #[must_use]
+    pub const fn with_no_hole_card(mut self, enabled: bool) -> Self {
+        self.no_hole_card = enabled;
+        self
+    }
+
+    /// Sets whether the dealer peeks for blackjack during the deal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_dealer_peek(true);
+    /// assert!(options.dealer_peek);
+    /// ```
+    #[must_use]
+    pub const fn with_dealer_peek(mut self, enabled: bool) -> Self {
+        self.dealer_peek = enabled;
+        self
+    }
+
+    /// Sets whether player naturals are paid during the deal when the dealer
+    /// cannot have blackjack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_immediate_blackjack_payout(true);
+    /// assert!(options.immediate_blackjack_payout);
+    /// ```
+    #[must_use]
+    pub const fn with_immediate_blackjack_payout(mut self, enabled: bool) -> Self {
+        self.immediate_blackjack_payout = enabled;
+        self
+    }
+
+    /// Sets the number of cards after which a player hand stands
+    /// automatically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_max_cards_per_hand(7);
+    /// assert_eq!(options.max_cards_per_hand, 7);
+    /// ```
+    #[must_use]
+    pub const fn with_max_cards_per_hand(mut self, max_cards: u8) -> Self {
+        self.max_cards_per_hand = max_cards;
+        self
+    }
+
+    /// Sets the number of cards for a Charlie, or `None` to disable it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_charlie(Some(5));
+    /// assert_eq!(options.charlie, Some(5));
+    /// ```
+    #[must_use]
+    pub const fn with_charlie(mut self, cards: Option<u8>) -> Self {
+        self.charlie = cards;
+        self
+    }
+
+    /// Sets whether the discard pile is shuffled back into an empty shoe.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::GameOptions;
+    ///
+    /// let options = GameOptions::default().with_reshuffle_on_empty(true);
+    /// assert!(options.reshuffle_on_empty);
+    /// ```
+    #[must_use]
+    pub const fn with_reshuffle_on_empty(mut self, enabled: bool) -> Self {
+        self.reshuffle_on_empty = enabled;
+        self
+    }
+
     /// Sets the deck penetration.
     ///
     /// # Example
@@ -299,4 +1150,125 @@ impl GameOptions {
         self.penetration = penetration;
         self
     }
+
+    /// Sets the table's bet limits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, GameOptions};
+    ///
+    /// let options = GameOptions::default().with_bet_limits(Chips::new(10), Chips::new(500));
+    /// assert_eq!(options.min_bet, Chips::new(10));
+    /// assert_eq!(options.max_bet, Chips::new(500));
+    /// ```
+    #[must_use]
+    pub const fn with_bet_limits(mut self, min: Chips, max: Chips) -> Self {
+        self.min_bet = min;
+        self.max_bet = max;
+        self
+    }
+
+    /// Sets the amount every bet must be a multiple of.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Chips, GameOptions};
+    ///
+    /// let options = GameOptions::default().with_bet_increment(Chips::new(5));
+    /// assert_eq!(options.bet_increment, Chips::new(5));
+    /// ```
+    #[must_use]
+    pub const fn with_bet_increment(mut self, increment: Chips) -> Self {
+        self.bet_increment = increment;
+        self
+    }
+
+    /// Sets the Perfect Pairs pay table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, Payout, PerfectPairs};
+    ///
+    /// let options = GameOptions::default().with_perfect_pairs(PerfectPairs {
+    ///     mixed: Payout::new(5, 1),
+    ///     ..PerfectPairs::default()
+    /// });
+    /// assert_eq!(options.perfect_pairs.mixed, Payout::new(5, 1));
+    /// ```
+    #[must_use]
+    pub const fn with_perfect_pairs(mut self, pays: PerfectPairs) -> Self {
+        self.perfect_pairs = pays;
+        self
+    }
+
+    /// Sets the Lucky Ladies pay table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, LuckyLadies, Payout};
+    ///
+    /// let options = GameOptions::default().with_lucky_ladies(LuckyLadies {
+    ///     any_twenty: Payout::new(3, 1),
+    ///     ..LuckyLadies::default()
+    /// });
+    /// assert_eq!(options.lucky_ladies.any_twenty, Payout::new(3, 1));
+    /// ```
+    #[must_use]
+    pub const fn with_lucky_ladies(mut self, pays: LuckyLadies) -> Self {
+        self.lucky_ladies = pays;
+        self
+    }
+
+    /// Sets the Lucky Lucky pay table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{GameOptions, LuckyLucky, Payout};
+    ///
+    /// let options = GameOptions::default().with_lucky_lucky(LuckyLucky {
+    ///     twenty_one: Payout::new(2, 1),
+    ///     ..LuckyLucky::default()
+    /// });
+    /// assert_eq!(options.lucky_lucky.twenty_one, Payout::new(2, 1));
+    /// ```
+    #[must_use]
+    pub const fn with_lucky_lucky(mut self, pays: LuckyLucky) -> Self {
+        self.lucky_lucky = pays;
+        self
+    }
+
+    /// Sets the Buster Blackjack pay ladder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bjrs::{Buster, GameOptions, Payout};
+    ///
+    /// let options = GameOptions::default().with_buster(Buster {
+    ///     six_cards: Payout::new(12, 1),
+    ///     ..Buster::default()
+    /// });
+    /// assert_eq!(options.buster.six_cards, Payout::new(12, 1));
+    /// ```
+    #[must_use]
+    pub const fn with_buster(mut self, pays: Buster) -> Self {
+        self.buster = pays;
+        self
+    }
+
+    /// Returns the pay table used for a kind of side bet.
+    #[must_use]
+    pub fn side_bet(&self, kind: SideBetKind) -> &dyn SideBet {
+        match kind {
+            SideBetKind::PerfectPairs => &self.perfect_pairs,
+            SideBetKind::LuckyLadies => &self.lucky_ladies,
+            SideBetKind::LuckyLucky => &self.lucky_lucky,
+            SideBetKind::Buster => &self.buster,
+        }
+    }
 }
@@ -0,0 +1,105 @@
+//! Exact payout ratios.
+
+use crate::chips::Chips;
+use crate::options::RoundingMode;
+
+/// A payout ratio of winnings to stake, such as 3:2 for a blackjack or 25:1
+/// for a perfect pair.
+///
+/// Winnings are computed with integer arithmetic, so a ratio like 6:5 pays
+/// exactly what the table says, and only a fractional result is rounded, by
+/// the [`RoundingMode`] the caller chooses.
+///
+/// Ratios are kept in lowest terms, so `Payout::new(6, 4)` equals
+/// `Payout::new(3, 2)`.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Chips, Payout, RoundingMode};
+///
+/// let six_to_five = Payout::new(6, 5);
+/// let winnings = six_to_five.winnings(Chips::new(25), RoundingMode::Down);
+/// assert_eq!(winnings, Chips::new(30));
+/// let three_to_two = Payout::new(3, 2);
+/// let winnings = three_to_two.winnings(Chips::new(15), RoundingMode::Down);
+/// assert_eq!(winnings, Chips::new(22));
+/// let winnings = three_to_two.winnings(Chips::new(15), RoundingMode::Up);
+/// assert_eq!(winnings, Chips::new(23));
+/// assert_eq!(Payout::new(6, 4), Payout::new(3, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Payout {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Payout {
+    /// Even money (1:1).
+    pub const EVEN: Self = Self::new(1, 1);
+
+    /// Half the stake (1:2), as refunded on a surrender.
+    pub const HALF: Self = Self::new(1, 2);
+
+    /// Creates a ratio paying `numerator` for every `denominator` staked.
+    ///
+    /// A zero denominator gives a ratio that is not
+    /// [valid](Self::is_valid), which
+    /// [`GameOptions::validate`](crate::GameOptions::validate) rejects.
+    #[must_use]
+    pub const fn new(numerator: u32, denominator: u32) -> Self {
+        if denominator == 0 {
+            return Self {
+                numerator,
+                denominator,
+            };
+        }
+        let (mut a, mut b) = (numerator, denominator);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        Self {
+            numerator: numerator / a,
+            denominator: denominator / a,
+        }
+    }
+
+    /// Returns the winnings side of the ratio, in lowest terms.
+    #[must_use]
+    pub const fn numerator(self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the stake side of the ratio, in lowest terms.
+    #[must_use]
+    pub const fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Returns whether the ratio can be paid, which is false only for a zero
+    /// denominator.
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        self.denominator != 0
+    }
+
+    /// Returns the winnings on `bet`, rounding a fractional amount with
+    /// `mode`.
+    ///
+    /// The stake is not included. An invalid ratio pays nothing.
+    #[must_use]
+    pub fn winnings(self, bet: Chips, mode: RoundingMode) -> Chips {
+        if !self.is_valid() {
+            return Chips::ZERO;
+        }
+        let product = u128::from(bet.get()) * u128::from(self.numerator);
+        let denominator = u128::from(self.denominator);
+        let winnings = match mode {
+            RoundingMode::Up => product.div_ceil(denominator),
+            RoundingMode::Down => product / denominator,
+            RoundingMode::Nearest => (product * 2 + denominator) / (denominator * 2),
+        };
+        Chips::new(u64::try_from(winnings).unwrap_or(u64::MAX))
+    }
+}
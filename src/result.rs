@@ -4,8 +4,12 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
+use crate::chips::Chips;
+use crate::side_bet::SideBetKind;
+
 /// Result of a single hand after showdown.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandOutcome {
     /// Player wins (dealer busts or player has higher value).
     Win,
@@ -17,51 +21,222 @@ pub enum HandOutcome {
     Blackjack,
     /// Player surrendered.
     Surrendered,
+    /// Player won with a Charlie.
+    Charlie,
+    /// Player won a Spanish 21 bonus.
+    Bonus,
 }
 
 /// Result for a single hand.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandResult {
     /// The hand index (for split hands).
     pub hand_index: usize,
     /// The outcome of the hand.
     pub outcome: HandOutcome,
     /// The bet amount for this hand.
-    pub bet: usize,
+    pub bet: Chips,
     /// The payout amount (winnings added to player money).
-    pub payout: usize,
+    pub payout: Chips,
+    /// Amount refunded before showdown (half the bet on a surrender).
+    pub refund: Chips,
     /// The player's hand value.
     pub player_value: u8,
     /// The dealer's hand value.
     pub dealer_value: u8,
 }
 
+impl HandResult {
+    /// Returns the net money movement for this hand (positive = profit).
+    ///
+    /// This is the payout plus any surrender refund, minus the final bet
+    /// (including a double down).
+    #[must_use]
+    pub const fn net(&self) -> i64 {
+        self.payout.saturating_add(self.refund).signed_sub(self.bet)
+    }
+}
+
+/// Result of a side bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SideBetResult {
+    /// Which side bet this was.
+    pub kind: SideBetKind,
+    /// The amount wagered.
+    pub bet: Chips,
+    /// The payout amount, stake included (0 if the bet lost).
+    pub payout: Chips,
+}
+
+impl SideBetResult {
+    /// Returns the net money movement for this side bet (positive = profit).
+    #[must_use]
+    pub const fn net(&self) -> i64 {
+        self.payout.signed_sub(self.bet)
+    }
+}
+
 /// Result for a single player after showdown.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerResult {
     /// The player ID.
     pub player_id: u8,
     /// Results for each hand (multiple if split).
     pub hands: Vec<HandResult>,
-    /// Total payout for all hands.
-    pub total_payout: usize,
+    /// Total payout for all hands, insurance and side bets.
+    pub total_payout: Chips,
     /// Net result (positive = profit, negative = loss).
-    pub net: isize,
+    pub net: i64,
     /// Insurance bet amount (0 if no insurance taken).
-    pub insurance_bet: usize,
+    pub insurance_bet: Chips,
     /// Insurance payout (0 if dealer didn't have blackjack or no insurance taken).
-    pub insurance_payout: usize,
+    pub insurance_payout: Chips,
+    /// Whether the insurance bet won ([`HandOutcome::Win`], the dealer had
+    /// blackjack) or lost ([`HandOutcome::Lose`]). `None` if no insurance was
+    /// taken.
+    pub insurance_outcome: Option<HandOutcome>,
+    /// Results of the player's side bets: those settled by the opening deal,
+    /// then those settled against the dealer's final hand, each in the order
+    /// they were placed.
+    pub side_bets: Vec<SideBetResult>,
+}
+
+impl PlayerResult {
+    /// Returns the net money movement from insurance (positive = profit).
+    #[must_use]
+    pub const fn insurance_net(&self) -> i64 {
+        self.insurance_payout.signed_sub(self.insurance_bet)
+    }
+}
+
+/// What a ledger entry settles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedgerSource {
+    /// A player hand, by index into the player's hands.
+    Hand(usize),
+    /// The player's insurance bet.
+    Insurance,
+    /// One of the player's side bets.
+    SideBet(SideBetKind),
+}
+
+/// A single money movement applied during showdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LedgerEntry {
+    /// The player ID.
+    pub player_id: u8,
+    /// What this entry settles.
+    pub source: LedgerSource,
+    /// Amount credited to the player's money by this entry.
+    pub credit: Chips,
+    /// Net result of the settled wager (positive = profit, negative = loss),
+    /// including any surrender refund paid before showdown.
+    pub net: i64,
 }
 
 /// Result of the entire round after showdown.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoundResult {
     /// Results for each player.
     pub players: Vec<PlayerResult>,
+    /// Money movements in the order they were credited: players in betting
+    /// order, each player's hands in index order, then their insurance, then
+    /// their side bets.
+    pub ledger: Vec<LedgerEntry>,
     /// The dealer's final hand value.
     pub dealer_value: u8,
     /// Whether the dealer busted.
     pub dealer_bust: bool,
     /// Whether the dealer had blackjack.
     pub dealer_blackjack: bool,
+    /// Total insurance wagered by all players this round.
+    pub total_insurance_bet: Chips,
+    /// Total insurance paid to all players this round, including the
+    /// returned insurance bets.
+    pub total_insurance_payout: Chips,
+}
+
+/// Aggregate results of a batch of automated rounds.
+///
+/// Hand outcome counts are disjoint: a natural counts toward `blackjacks` only,
+/// and a surrendered hand toward `surrenders` only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationReport {
+    /// Number of rounds played.
+    pub rounds: usize,
+    /// Number of hands settled, including split hands.
+    pub hands: usize,
+    /// Total amount wagered, including doubles, splits, insurance, and side
+    /// bets.
+    pub total_wagered: Chips,
+    /// Total amount returned to players, including stakes and surrender refunds.
+    pub total_returned: Chips,
+    /// Hands won, including Charlies and bonuses (excluding blackjacks).
+    pub wins: usize,
+    /// Hands lost (excluding surrenders).
+    pub losses: usize,
+    /// Hands pushed.
+    pub pushes: usize,
+    /// Hands paid as blackjack.
+    pub blackjacks: usize,
+    /// Hands surrendered.
+    pub surrenders: usize,
+}
+
+impl SimulationReport {
+    /// Adds the results of one round to the report.
+    pub fn record(&mut self, result: &RoundResult) {
+        self.rounds += 1;
+        for player in &result.players {
+            self.total_wagered = self.total_wagered.saturating_add(player.insurance_bet);
+            self.total_returned = self.total_returned.saturating_add(player.insurance_payout);
+            for side_bet in &player.side_bets {
+                self.total_wagered = self.total_wagered.saturating_add(side_bet.bet);
+                self.total_returned = self.total_returned.saturating_add(side_bet.payout);
+            }
+            for hand in &player.hands {
+                self.hands += 1;
+                self.total_wagered = self.total_wagered.saturating_add(hand.bet);
+                self.total_returned = self
+                    .total_returned
+                    .saturating_add(hand.payout.saturating_add(hand.refund));
+                match hand.outcome {
+                    HandOutcome::Win | HandOutcome::Charlie | HandOutcome::Bonus => self.wins += 1,
+                    HandOutcome::Lose => self.losses += 1,
+                    HandOutcome::Push => self.pushes += 1,
+                    HandOutcome::Blackjack => self.blackjacks += 1,
+                    HandOutcome::Surrendered => self.surrenders += 1,
+                }
+            }
+        }
+    }
+
+    /// Returns the net amount won by players (negative when the house won).
+    #[must_use]
+    pub const fn net(&self) -> i64 {
+        self.total_returned.signed_sub(self.total_wagered)
+    }
+
+    /// Returns the realized player edge: net result divided by total wagered.
+    ///
+    /// Negative values mean the house came out ahead. Returns `0.0` if nothing
+    /// was wagered.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "f64 has sufficient precision for realized edge"
+    )]
+    pub fn edge(&self) -> f64 {
+        if self.total_wagered.is_zero() {
+            return 0.0;
+        }
+        self.net() as f64 / self.total_wagered.get() as f64
+    }
 }
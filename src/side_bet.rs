@@ -0,0 +1,346 @@
+//! Side bets placed next to the main bet.
+
+use crate::card::{Card, Suit};
+use crate::hand::{DealerHand, evaluate_cards};
+use crate::payout::Payout;
+
+/// A side bet settled from the player's opening cards and the dealer's hand.
+///
+/// Implementations look at the player's opening cards and the dealer's hand
+/// and return the [`Payout`] ratio of winnings to stake, or `None` if the
+/// bet loses. The stake is returned on top of the winnings.
+///
+/// By default the dealer's hand is the opening one (up card and, when dealt,
+/// hole card), and the bet is settled right after the deal. A side bet that
+/// returns `true` from [`settles_at_showdown`](Self::settles_at_showdown) is
+/// settled at showdown against the dealer's final hand instead.
+pub trait SideBet {
+    /// Returns the payout ratio, or `None` if the bet loses.
+    fn payout_ratio(&self, player_cards: &[Card], dealer: &DealerHand) -> Option<Payout>;
+
+    /// Returns whether the bet waits for the dealer's final hand.
+    fn settles_at_showdown(&self) -> bool {
+        false
+    }
+}
+
+/// The side bets a game offers, placed with
+/// [`Game::place_side_bet`](crate::Game::place_side_bet).
+///
+/// Each kind pays from the pay table of the same name in
+/// [`GameOptions`](crate::GameOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SideBetKind {
+    /// [`PerfectPairs`].
+    PerfectPairs,
+    /// [`LuckyLadies`].
+    LuckyLadies,
+    /// [`LuckyLucky`].
+    LuckyLucky,
+    /// [`Buster`].
+    Buster,
+}
+
+/// How closely a pair matches, from [`PerfectPairs::pair_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PairKind {
+    /// Same rank, one red card and one black card.
+    Mixed,
+    /// Same rank and color, different suits.
+    Colored,
+    /// Same rank and suit.
+    Perfect,
+}
+
+/// The Perfect Pairs side bet: wins when the player's first two cards are a
+/// pair.
+///
+/// The best matching line pays.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, DealerHand, Payout, PerfectPairs, SideBet, Suit};
+///
+/// let pairs = PerfectPairs::default();
+/// let dealer = DealerHand::new();
+/// let cards = [Card::new(Suit::Hearts, 8), Card::new(Suit::Diamonds, 8)];
+/// assert_eq!(pairs.payout_ratio(&cards, &dealer), Some(Payout::new(12, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerfectPairs {
+    /// Winnings ratio for a mixed pair (6:1 by default).
+    pub mixed: Payout,
+    /// Winnings ratio for a colored pair (12:1 by default).
+    pub colored: Payout,
+    /// Winnings ratio for a perfect pair (25:1 by default).
+    pub perfect: Payout,
+}
+
+impl Default for PerfectPairs {
+    fn default() -> Self {
+        Self {
+            mixed: Payout::new(6, 1),
+            colored: Payout::new(12, 1),
+            perfect: Payout::new(25, 1),
+        }
+    }
+}
+
+impl PerfectPairs {
+    /// Returns what kind of pair the first two cards form, or `None` if they
+    /// are not a pair.
+    ///
+    /// Cards pair by rank, so a jack and a king are not a pair.
+    #[must_use]
+    pub fn pair_kind(cards: &[Card]) -> Option<PairKind> {
+        let [first, second, ..] = cards else {
+            return None;
+        };
+        if first.rank != second.rank {
+            return None;
+        }
+        Some(if first.suit == second.suit {
+            PairKind::Perfect
+        } else if first.suit.color() == second.suit.color() {
+            PairKind::Colored
+        } else {
+            PairKind::Mixed
+        })
+    }
+}
+
+impl SideBet for PerfectPairs {
+    fn payout_ratio(&self, player_cards: &[Card], _dealer: &DealerHand) -> Option<Payout> {
+        Self::pair_kind(player_cards).map(|kind| match kind {
+            PairKind::Mixed => self.mixed,
+            PairKind::Colored => self.colored,
+            PairKind::Perfect => self.perfect,
+        })
+    }
+}
+
+/// The Lucky Ladies side bet: wins when the player's first two cards total
+/// 20.
+///
+/// An ace counts as 11, so an ace and a nine is a 20. The best matching line
+/// pays.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, DealerHand, LuckyLadies, Payout, SideBet, Suit};
+///
+/// let ladies = LuckyLadies::default();
+/// let dealer = DealerHand::new();
+/// let cards = [Card::new(Suit::Hearts, 12), Card::new(Suit::Hearts, 12)];
+/// assert_eq!(ladies.payout_ratio(&cards, &dealer), Some(Payout::new(200, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuckyLadies {
+    /// Winnings ratio for any 20 (4:1 by default).
+    pub any_twenty: Payout,
+    /// Winnings ratio for a 20 of one suit (10:1 by default).
+    pub suited_twenty: Payout,
+    /// Winnings ratio for a 20 of two cards of the same rank and suit (25:1
+    /// by default).
+    pub matched_twenty: Payout,
+    /// Winnings ratio for a pair of queens of hearts (200:1 by default).
+    pub queen_of_hearts_pair: Payout,
+    /// Winnings ratio for a pair of queens of hearts when the dealer has
+    /// blackjack (1000:1 by default).
+    pub queen_of_hearts_pair_with_dealer_blackjack: Payout,
+}
+
+impl Default for LuckyLadies {
+    fn default() -> Self {
+        Self {
+            any_twenty: Payout::new(4, 1),
+            suited_twenty: Payout::new(10, 1),
+            matched_twenty: Payout::new(25, 1),
+            queen_of_hearts_pair: Payout::new(200, 1),
+            queen_of_hearts_pair_with_dealer_blackjack: Payout::new(1000, 1),
+        }
+    }
+}
+
+impl SideBet for LuckyLadies {
+    fn payout_ratio(&self, player_cards: &[Card], dealer: &DealerHand) -> Option<Payout> {
+        let [first, second, ..] = player_cards else {
+            return None;
+        };
+        if evaluate_cards(&[*first, *second]).0 != 20 {
+            return None;
+        }
+
+        let queen_of_hearts = Card::new(Suit::Hearts, 12);
+        Some(if *first == queen_of_hearts && *second == queen_of_hearts {
+            if dealer.is_blackjack() {
+                self.queen_of_hearts_pair_with_dealer_blackjack
+            } else {
+                self.queen_of_hearts_pair
+            }
+        } else if first == second {
+            self.matched_twenty
+        } else if first.suit == second.suit {
+            self.suited_twenty
+        } else {
+            self.any_twenty
+        })
+    }
+}
+
+/// The Lucky Lucky side bet: wins when the player's first two cards and the
+/// dealer's up card total 19, 20 or 21.
+///
+/// Aces count as 1 or 11, whichever is best. "Suited" means all three cards
+/// share a suit. The best matching line pays.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Card, DealerHand, LuckyLucky, Payout, SideBet, Suit};
+///
+/// let lucky = LuckyLucky::default();
+/// let mut dealer = DealerHand::new();
+/// dealer.add_card(Card::new(Suit::Spades, 8));
+/// let cards = [Card::new(Suit::Spades, 6), Card::new(Suit::Spades, 7)];
+/// assert_eq!(lucky.payout_ratio(&cards, &dealer), Some(Payout::new(100, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuckyLucky {
+    /// Winnings ratio for a total of 19 or 20 (2:1 by default).
+    pub nineteen_or_twenty: Payout,
+    /// Winnings ratio for an unsuited 21 (3:1 by default).
+    pub twenty_one: Payout,
+    /// Winnings ratio for a suited 21 (15:1 by default).
+    pub suited_twenty_one: Payout,
+    /// Winnings ratio for an unsuited 6-7-8 (30:1 by default).
+    pub six_seven_eight: Payout,
+    /// Winnings ratio for an unsuited 7-7-7 (50:1 by default).
+    pub seven_seven_seven: Payout,
+    /// Winnings ratio for a suited 6-7-8 (100:1 by default).
+    pub suited_six_seven_eight: Payout,
+    /// Winnings ratio for a suited 7-7-7 (200:1 by default).
+    pub suited_seven_seven_seven: Payout,
+}
+
+impl Default for LuckyLucky {
+    fn default() -> Self {
+        Self {
+            nineteen_or_twenty: Payout::new(2, 1),
+            twenty_one: Payout::new(3, 1),
+            suited_twenty_one: Payout::new(15, 1),
+            six_seven_eight: Payout::new(30, 1),
+            seven_seven_seven: Payout::new(50, 1),
+            suited_six_seven_eight: Payout::new(100, 1),
+            suited_seven_seven_seven: Payout::new(200, 1),
+        }
+    }
+}
+
+impl SideBet for LuckyLucky {
+    fn payout_ratio(&self, player_cards: &[Card], dealer: &DealerHand) -> Option<Payout> {
+        let ([first, second, ..], Some(&up_card)) = (player_cards, dealer.up_card()) else {
+            return None;
+        };
+        let cards = [*first, *second, up_card];
+        let suited = cards.iter().all(|card| card.suit == first.suit);
+        let mut ranks = cards.map(|card| card.rank);
+        ranks.sort_unstable();
+
+        match (evaluate_cards(&cards).0, ranks, suited) {
+            (21, [6, 7, 8], true) => Some(self.suited_six_seven_eight),
+            (21, [7, 7, 7], true) => Some(self.suited_seven_seven_seven),
+            (21, [6, 7, 8], false) => Some(self.six_seven_eight),
+            (21, [7, 7, 7], false) => Some(self.seven_seven_seven),
+            (21, _, true) => Some(self.suited_twenty_one),
+            (21, _, false) => Some(self.twenty_one),
+            (19 | 20, _, _) => Some(self.nineteen_or_twenty),
+            _ => None,
+        }
+    }
+}
+
+/// The Buster Blackjack side bet: wins when the dealer busts, paying more
+/// the more cards the dealer busts with.
+///
+/// It is settled at showdown against the dealer's final hand. If every
+/// player hand busts or surrenders, the dealer does not draw, so the bet
+/// loses.
+///
+/// # Example
+///
+/// ```
+/// use bjrs::{Buster, Card, DealerHand, Payout, SideBet, Suit};
+///
+/// let buster = Buster::default();
+/// let dealer = DealerHand::from_cards(
+///     &[
+///         Card::new(Suit::Spades, 2),
+///         Card::new(Suit::Hearts, 4),
+///         Card::new(Suit::Clubs, 5),
+///         Card::new(Suit::Diamonds, 3),
+///         Card::new(Suit::Spades, 10),
+///     ],
+///     true,
+/// );
+/// assert_eq!(buster.payout_ratio(&[], &dealer), Some(Payout::new(4, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Buster {
+    /// Winnings ratio for a bust with three cards (2:1 by default).
+    pub three_cards: Payout,
+    /// Winnings ratio for a bust with four cards (2:1 by default).
+    pub four_cards: Payout,
+    /// Winnings ratio for a bust with five cards (4:1 by default).
+    pub five_cards: Payout,
+    /// Winnings ratio for a bust with six cards (15:1 by default).
+    pub six_cards: Payout,
+    /// Winnings ratio for a bust with seven cards (50:1 by default).
+    pub seven_cards: Payout,
+    /// Winnings ratio for a bust with eight or more cards (250:1 by
+    /// default).
+    pub eight_or_more_cards: Payout,
+}
+
+impl Default for Buster {
+    fn default() -> Self {
+        Self {
+            three_cards: Payout::new(2, 1),
+            four_cards: Payout::new(2, 1),
+            five_cards: Payout::new(4, 1),
+            six_cards: Payout::new(15, 1),
+            seven_cards: Payout::new(50, 1),
+            eight_or_more_cards: Payout::new(250, 1),
+        }
+    }
+}
+
+impl SideBet for Buster {
+    fn payout_ratio(&self, _player_cards: &[Card], dealer: &DealerHand) -> Option<Payout> {
+        if !dealer.is_bust() {
+            return None;
+        }
+        match dealer.len() {
+            0..=2 => None,
+            3 => Some(self.three_cards),
+            4 => Some(self.four_cards),
+            5 => Some(self.five_cards),
+            6 => Some(self.six_cards),
+            7 => Some(self.seven_cards),
+            _ => Some(self.eight_or_more_cards),
+        }
+    }
+
+    fn settles_at_showdown(&self) -> bool {
+        true
+    }
+}
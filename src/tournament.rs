@@ -0,0 +1,153 @@
+//! Tournament play with eliminations and chip leaderboards.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::chips::Chips;
+use crate::error::RoundError;
+use crate::game::{Game, PlayerDecision};
+use crate::hand::Hand;
+use crate::options::GameOptions;
+use crate::result::RoundResult;
+
+/// A fixed-length tournament played on a single [`Game`].
+///
+/// Every round each remaining player bets the same flat amount, or the largest
+/// valid bet their stack covers, if smaller. Players whose stack can no longer
+/// cover the table's `min_bet` are eliminated and removed from the game, and
+/// any chips they had left are forfeited. The tournament ends after the
+/// configured number of rounds or once every player has been eliminated.
+pub struct Tournament {
+    /// The underlying game.
+    game: Game,
+    /// Number of rounds to play.
+    rounds: usize,
+    /// Number of rounds played so far.
+    rounds_played: usize,
+    /// Eliminated player IDs, in elimination order.
+    eliminated: Vec<u8>,
+}
+
+impl Tournament {
+    /// Creates a tournament of `rounds` rounds on the given game.
+    ///
+    /// Players should already have joined the game.
+    #[must_use]
+    pub const fn new(game: Game, rounds: usize) -> Self {
+        Self {
+            game,
+            rounds,
+            rounds_played: 0,
+            eliminated: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying game.
+    #[must_use]
+    pub const fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Consumes the tournament and returns the underlying game.
+    #[must_use]
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+
+    /// Returns the number of rounds played so far.
+    #[must_use]
+    pub const fn rounds_played(&self) -> usize {
+        self.rounds_played
+    }
+
+    /// Returns the eliminated player IDs, in elimination order.
+    #[must_use]
+    pub fn eliminated(&self) -> &[u8] {
+        &self.eliminated
+    }
+
+    /// Returns whether the tournament has finished.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.rounds_played >= self.rounds || self.game.player_count() == 0
+    }
+
+    /// Plays one tournament round with a flat bet.
+    ///
+    /// See [`Game::play_round`] for the meaning of `strategy`. Players left
+    /// unable to cover the table minimum afterwards are eliminated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoundError::TournamentOver`] if the tournament has finished,
+    /// [`RoundError::Bet`] if `bet` is outside the table limits, or any error
+    /// from [`Game::play_round`].
+    pub fn play_round(
+        &mut self,
+        bet: Chips,
+        strategy: impl FnMut(u8, &Hand, Option<Card>) -> PlayerDecision,
+    ) -> Result<RoundResult, RoundError> {
+        if self.is_over() {
+            return Err(RoundError::TournamentOver);
+        }
+
+        let options = self.game.options();
+        Game::check_bet(&options, bet)?;
+
+        let players = self.game.players.lock().clone();
+        let bets: Vec<(u8, Chips)> = players
+            .iter()
+            .filter_map(|&id| Some((id, Self::stake(&options, bet, self.game.get_money(id)?)?)))
+            .collect();
+
+        let result = self.game.play_round(&bets, strategy)?;
+        self.rounds_played += 1;
+
+        for id in players {
+            let money = self.game.get_money(id).unwrap_or_default();
+            if Self::stake(&options, bet, money).is_none() {
+                self.game.leave(id);
+                self.eliminated.push(id);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the bet a stack of `money` places: `bet`, or the largest valid
+    /// bet the stack covers. Returns `None` if it cannot cover `min_bet`.
+    fn stake(options: &GameOptions, bet: Chips, money: Chips) -> Option<Chips> {
+        let hands = Game::hands_per_player(options) as u64;
+        let mut amount = bet.min(Chips::new(money.get() / hands));
+        if let Some(rest) = amount.checked_rem(options.bet_increment) {
+            amount = amount.saturating_sub(rest);
+        }
+        Game::check_bet(options, amount).ok().map(|_| amount)
+    }
+
+    /// Returns the standings as `(player_id, chips)`.
+    ///
+    /// Remaining players come first, sorted by chips (most first, ties broken
+    /// by player ID), followed by eliminated players with zero chips, most
+    /// recently eliminated first.
+    #[must_use]
+    pub fn leaderboard(&self) -> Vec<(u8, Chips)> {
+        let mut board = self.game.standings();
+        board.extend(self.eliminated.iter().rev().map(|&id| (id, Chips::ZERO)));
+        board
+    }
+
+    /// Returns the leading player once the tournament is over.
+    #[must_use]
+    pub fn winner(&self) -> Option<u8> {
+        if !self.is_over() {
+            return None;
+        }
+        self.leaderboard()
+            .first()
+            .filter(|&&(_, chips)| !chips.is_zero())
+            .map(|&(id, _)| id)
+    }
+}
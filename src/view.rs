@@ -0,0 +1,58 @@
+//! Read-only table views with hidden information removed.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::game::GameState;
+use crate::hand::{DealerHand, Hand};
+
+/// The dealer's hand as a spectator sees it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DealerView {
+    /// Cards in dealing order; `None` marks a card that is still face down.
+    pub cards: Vec<Option<Card>>,
+    /// Value of the face-up cards.
+    pub visible_value: u8,
+}
+
+impl From<&DealerHand> for DealerView {
+    fn from(dealer: &DealerHand) -> Self {
+        let hole_revealed = dealer.is_hole_revealed();
+        let cards = dealer
+            .cards()
+            .iter()
+            .enumerate()
+            .map(|(index, card)| (hole_revealed || index == 0).then_some(*card))
+            .collect();
+
+        Self {
+            cards,
+            visible_value: dealer.visible_value(),
+        }
+    }
+}
+
+/// A seated player as a spectator sees them.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerView {
+    /// The player ID.
+    pub player_id: u8,
+    /// The player's hands this round (empty if they are not in the round).
+    pub hands: Vec<Hand>,
+}
+
+/// A snapshot of the table that is safe to show to spectators.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpectatorView {
+    /// Current game state.
+    pub state: GameState,
+    /// The dealer's hand with face-down cards hidden.
+    pub dealer: DealerView,
+    /// Seated players in seat order.
+    pub players: Vec<PlayerView>,
+}
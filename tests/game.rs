@@ -3,14 +3,23 @@
 #![allow(clippy::float_cmp)]
 
 use bjrs::{
-    ActionError, BetError, Card, DECK_SIZE, DealError, DoubleOption, Game, GameOptions, GameState,
-    Hand, HandStatus, InsuranceError, RoundingMode, ShowdownError, Suit,
+    Action, ActionError, ActionSet, BetError, BlackjackQualifier, Card, CardRecipient, Chips,
+    Color, DECK_SIZE, DealError, DealOrder, DealerHand, DoubleOption, FundsError, Game, GameEvent,
+    GameOptions, GameState, Hand, HandOutcome, HandStatus, InsuranceError, LedgerSource,
+    LoggedAction, LuckyLadies, LuckyLucky, OptionsError, Payout, PlayerDecision, ReplayError,
+    RoundError, RoundingMode, SPANISH_DECK_SIZE, ShoeError, ShowdownError, SideBetKind,
+    SideBetResult, SplitDenyReason, StandingHint, StateError, Suit, SurrenderRule, Tie, Tournament,
+    compare_blackjack, standard_deck,
 };
 
 const fn card(suit: Suit, rank: u8) -> Card {
     Card::new(suit, rank)
 }
 
+const fn chips(amount: u64) -> Chips {
+    Chips::new(amount)
+}
+
 fn set_deck_from_draws(game: &Game, draws: &[Card]) {
     let mut deck: Vec<Card> = draws.to_vec();
     deck.reverse();
@@ -19,19 +28,19 @@ fn set_deck_from_draws(game: &Game, draws: &[Card]) {
 
 #[test]
 fn hand_blackjack_and_split_behavior() {
-    let mut hand = Hand::new(10);
+    let mut hand = Hand::new(chips(10));
     hand.add_card(card(Suit::Hearts, 1));
     hand.add_card(card(Suit::Spades, 13));
     assert_eq!(hand.value(), 21);
     assert_eq!(hand.status(), HandStatus::Blackjack);
     assert!(hand.is_soft());
 
-    let mut split_hand = Hand::from_split(card(Suit::Hearts, 1), 10);
+    let mut split_hand = Hand::from_split(card(Suit::Hearts, 1), chips(10));
     split_hand.add_card(card(Suit::Clubs, 13));
     assert_eq!(split_hand.value(), 21);
     assert_eq!(split_hand.status(), HandStatus::Active);
 
-    let mut bust_hand = Hand::new(5);
+    let mut bust_hand = Hand::new(chips(5));
     bust_hand.add_card(card(Suit::Hearts, 10));
     bust_hand.add_card(card(Suit::Spades, 10));
     bust_hand.add_card(card(Suit::Diamonds, 2));
@@ -57,28 +66,28 @@ fn dealer_hand_visibility_and_values() {
 fn options_builder_sets_fields() {
     let options = GameOptions::default()
         .with_decks(4)
-        .with_blackjack_pays(1.2)
+        .with_blackjack_pays(Payout::new(6, 5))
         .with_stand_on_soft_17(false)
         .with_double(DoubleOption::NineOrTen)
         .with_split(1)
         .with_double_after_split(false)
         .with_split_aces_only_once(false)
         .with_split_aces_receive_one_card(false)
-        .with_surrender(false)
+        .with_surrender(SurrenderRule::None)
         .with_insurance(false)
         .with_rounding_blackjack(RoundingMode::Up)
         .with_rounding_surrender(RoundingMode::Down)
         .with_penetration(0.5);
 
     assert_eq!(options.decks, 4);
-    assert_eq!(options.blackjack_pays, 1.2);
+    assert_eq!(options.blackjack_pays, Payout::new(6, 5));
     assert!(!options.stand_on_soft_17);
     assert_eq!(options.double, DoubleOption::NineOrTen);
     assert_eq!(options.split, 1);
     assert!(!options.double_after_split);
     assert!(!options.split_aces_only_once);
     assert!(!options.split_aces_receive_one_card);
-    assert!(!options.surrender);
+    assert_eq!(options.surrender, SurrenderRule::None);
     assert!(!options.insurance);
     assert_eq!(options.rounding_blackjack, RoundingMode::Up);
     assert_eq!(options.rounding_surrender, RoundingMode::Down);
@@ -100,18 +109,21 @@ fn reshuffle_when_penetration_reached() {
 fn bet_errors() {
     let options = GameOptions::default().with_insurance(false);
     let game = Game::new(options, 1);
-    let player = game.join(10);
+    let player = game.join(chips(10));
 
-    assert_eq!(game.bet(player, 5).unwrap_err(), BetError::InvalidState);
+    assert_eq!(
+        game.bet(player, chips(5)).unwrap_err(),
+        BetError::InvalidState
+    );
 
     game.start_betting();
-    assert_eq!(game.bet(player, 0).unwrap_err(), BetError::ZeroBet);
+    assert_eq!(game.bet(player, chips(0)).unwrap_err(), BetError::ZeroBet);
     assert_eq!(
-        game.bet(player, 20).unwrap_err(),
+        game.bet(player, chips(20)).unwrap_err(),
         BetError::InsufficientFunds
     );
     assert_eq!(
-        game.bet(player + 1, 1).unwrap_err(),
+        game.bet(player + 1, chips(1)).unwrap_err(),
         BetError::PlayerNotFound
     );
 }
@@ -126,8 +138,8 @@ fn deal_errors() {
     game.start_betting();
     assert_eq!(game.deal().unwrap_err(), DealError::NoBets);
 
-    let player = game.join(10);
-    game.bet(player, 5).unwrap();
+    let player = game.join(chips(10));
+    game.bet(player, chips(5)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -145,10 +157,10 @@ fn deal_errors() {
 fn hit_with_empty_shoe_returns_error() {
     let options = GameOptions::default().with_insurance(false);
     let game = Game::new(options, 7);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -170,10 +182,10 @@ fn hit_with_empty_shoe_returns_error() {
 fn basic_round_flow() {
     let options = GameOptions::default().with_insurance(false);
     let game = Game::new(options, 42);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -203,17 +215,17 @@ fn basic_round_flow() {
     let result = game.showdown().unwrap();
     assert_eq!(result.players.len(), 1);
     assert_eq!(result.dealer_value, 21);
-    assert_eq!(game.get_money(player), Some(90));
+    assert_eq!(game.get_money(player), Some(chips(90)));
 }
 
 #[test]
 fn insurance_flow_with_dealer_blackjack() {
     let options = GameOptions::default().with_insurance(true);
     let game = Game::new(options, 99);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -230,25 +242,25 @@ fn insurance_flow_with_dealer_blackjack() {
     assert!(game.is_insurance_offered());
 
     let insurance_bet = game.take_insurance(player).unwrap();
-    assert_eq!(insurance_bet, 5);
+    assert_eq!(insurance_bet, chips(5));
 
     let dealer_blackjack = game.finish_insurance().unwrap();
     assert!(dealer_blackjack);
     assert_eq!(*game.state.lock(), GameState::RoundOver);
 
     let result = game.showdown().unwrap();
-    assert_eq!(result.players[0].insurance_payout, 15);
-    assert_eq!(game.get_money(player), Some(100));
+    assert_eq!(result.players[0].insurance_payout, chips(15));
+    assert_eq!(game.get_money(player), Some(chips(100)));
 }
 
 #[test]
 fn insurance_keeps_player_turn_when_active() {
     let options = GameOptions::default().with_insurance(true);
     let game = Game::new(options, 77);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -276,10 +288,10 @@ fn double_down_allowed_and_updates_bet() {
         .with_insurance(false)
         .with_double(DoubleOption::NineOrTen);
     let game = Game::new(options, 5);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -298,7 +310,7 @@ fn double_down_allowed_and_updates_bet() {
     assert_eq!(*game.state.lock(), GameState::DealerTurn);
 
     let hands = game.get_hands(player).unwrap();
-    assert_eq!(hands[0].bet(), 20);
+    assert_eq!(hands[0].bet(), chips(20));
 }
 
 #[test]
@@ -307,10 +319,10 @@ fn double_down_rejected_when_value_not_allowed() {
         .with_insurance(false)
         .with_double(DoubleOption::NineOrTen);
     let game = Game::new(options, 6);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -333,10 +345,10 @@ fn double_down_rejected_when_value_not_allowed() {
 fn split_creates_two_hands() {
     let options = GameOptions::default().with_insurance(false);
     let game = Game::new(options, 11);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -357,19 +369,71 @@ fn split_creates_two_hands() {
     assert_eq!(hands.len(), 2);
     assert_eq!(hands[0].len(), 2);
     assert_eq!(hands[1].len(), 2);
-    assert_eq!(game.get_money(player), Some(80));
+    assert_eq!(game.get_money(player), Some(chips(80)));
+}
+
+#[test]
+fn failed_double_down_keeps_the_stake() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 11);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal_from(&[
+        card(Suit::Hearts, 5),
+        card(Suit::Clubs, 10),
+        card(Suit::Diamonds, 6),
+        card(Suit::Spades, 7),
+    ])
+    .unwrap();
+
+    assert_eq!(game.double_down(player, 0), Err(ActionError::NoCards));
+    let hands = game.get_hands(player).unwrap();
+    assert_eq!(hands[0].len(), 2);
+    assert_eq!(hands[0].bet(), chips(10));
+    assert!(!hands[0].is_doubled());
+    assert_eq!(game.get_money(player), Some(chips(90)));
+    assert_eq!(game.current_player(), Some(player));
+}
+
+#[test]
+fn failed_split_leaves_hand_and_money_untouched() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 11);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    // No cards are left for the split hands
+    game.deal_from(&[
+        card(Suit::Hearts, 8),
+        card(Suit::Clubs, 5),
+        card(Suit::Diamonds, 8),
+        card(Suit::Spades, 9),
+    ])
+    .unwrap();
+
+    assert_eq!(game.split(player, 0), Err(ActionError::NoCards));
+    let hands = game.get_hands(player).unwrap();
+    assert_eq!(hands.len(), 1);
+    assert_eq!(hands[0].len(), 2);
+    assert_eq!(hands[0].bet(), chips(10));
+    assert_eq!(game.get_money(player), Some(chips(90)));
+    assert_eq!(game.current_player(), Some(player));
+    game.stand(player, 0).unwrap();
 }
 
 #[test]
 fn surrender_refunds_half_bet() {
     let options = GameOptions::default()
         .with_insurance(false)
-        .with_surrender(true);
+        .with_surrender(SurrenderRule::Late);
     let game = Game::new(options, 21);
-    let player = game.join(100);
+    let player = game.join(chips(100));
 
     game.start_betting();
-    game.bet(player, 10).unwrap();
+    game.bet(player, chips(10)).unwrap();
 
     set_deck_from_draws(
         &game,
@@ -383,8 +447,8 @@ fn surrender_refunds_half_bet() {
 
     game.deal().unwrap();
     let refund = game.surrender(player, 0).unwrap();
-    assert_eq!(refund, 5);
-    assert_eq!(game.get_money(player), Some(95));
+    assert_eq!(refund, chips(5));
+    assert_eq!(game.get_money(player), Some(chips(95)));
     assert_eq!(*game.state.lock(), GameState::DealerTurn);
 }
 
@@ -402,3 +466,4232 @@ fn insurance_rejects_wrong_state() {
         InsuranceError::InvalidState
     );
 }
+
+#[test]
+fn low_shoe_callback_fires_once_per_shoe() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static FIRED: AtomicUsize = AtomicUsize::new(0);
+
+    let options = GameOptions::default()
+        .with_decks(1)
+        .with_penetration(0.5)
+        .with_insurance(false);
+    let game = Game::new(options, 3);
+    let player = game.join(chips(100));
+
+    game.set_low_shoe_callback(|| {
+        FIRED.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // 30 of 52 cards left: the fourth draw of the deal crosses 50% penetration.
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 30];
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+
+    game.hit(player, 0).unwrap();
+    game.hit(player, 0).unwrap();
+    assert_eq!(FIRED.load(Ordering::SeqCst), 1);
+
+    game.clear_round();
+    game.reshuffle().unwrap();
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 27];
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(FIRED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn low_shoe_callback_can_call_back_into_the_game() {
+    use std::sync::{Mutex, OnceLock};
+
+    static GAME: OnceLock<Game> = OnceLock::new();
+    static SEEN: Mutex<Option<(GameState, usize)>> = Mutex::new(None);
+
+    let options = GameOptions::default()
+        .with_decks(1)
+        .with_penetration(0.5)
+        .with_insurance(false);
+    let game = GAME.get_or_init(|| Game::new(options, 3));
+    let player = game.join(chips(100));
+
+    game.set_low_shoe_callback(|| {
+        let game = GAME.get().unwrap();
+        *SEEN.lock().unwrap() = Some((game.state(), game.cards_remaining()));
+    });
+
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 30];
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    // The callback runs once the deal is over
+    assert_eq!(*SEEN.lock().unwrap(), Some((GameState::PlayerTurn, 26)));
+}
+
+#[test]
+fn round_results_are_ordered_deterministically() {
+    fn play(seed: u64) -> Vec<(u8, i64)> {
+        let options = GameOptions::default().with_insurance(false);
+        let game = Game::new(options, seed);
+        let players: Vec<u8> = (0..3).map(|_| game.join(chips(100))).collect();
+
+        game.start_betting();
+        for &player in players.iter().rev() {
+            game.bet(player, chips(10)).unwrap();
+        }
+        game.deal().unwrap();
+
+        while let Some(player) = game.current_player() {
+            let turn = game.current_turn();
+            game.stand(player, turn.hand_index).unwrap();
+        }
+        if game.state() == GameState::DealerTurn {
+            game.dealer_play().unwrap();
+        }
+
+        let money_order: Vec<u8> = game.money.lock().keys().copied().collect();
+        assert_eq!(money_order, players);
+
+        game.showdown()
+            .unwrap()
+            .players
+            .iter()
+            .map(|player| (player.player_id, player.net))
+            .collect()
+    }
+
+    let first = play(2024);
+    let second = play(2024);
+    assert_eq!(first, second);
+    assert_eq!(
+        first.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+}
+
+#[test]
+fn insurance_affordability_at_half_bet_boundary() {
+    let game = Game::new(GameOptions::default(), 8);
+    let exact = game.join(chips(15));
+    let short = game.join(chips(14));
+    let idle = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(exact, chips(10)).unwrap();
+    game.bet(short, chips(10)).unwrap();
+
+    assert!(game.can_afford_insurance(exact));
+    assert!(!game.can_afford_insurance(short));
+    assert!(!game.can_afford_insurance(idle));
+    assert!(!game.can_afford_insurance(99));
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),   // exact
+            card(Suit::Clubs, 9),    // short
+            card(Suit::Spades, 1),   // dealer up (Ace)
+            card(Suit::Hearts, 13),  // exact (natural)
+            card(Suit::Clubs, 7),    // short
+            card(Suit::Diamonds, 6), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+    assert!(game.is_insurance_offered());
+
+    assert!(game.can_afford_insurance(exact));
+    assert_eq!(
+        game.take_insurance_amount(exact, chips(5)).unwrap(),
+        chips(5)
+    );
+    assert_eq!(game.get_money(exact), Some(chips(0)));
+    assert_eq!(
+        game.take_even_money_or_insurance(short).unwrap_err(),
+        InsuranceError::InsufficientFunds
+    );
+}
+
+#[test]
+fn take_even_money_or_insurance_pays_even_money_on_a_natural() {
+    let options = GameOptions::default().with_blackjack_pays(Payout::new(6, 5));
+    let game = Game::new(options, 8);
+    let natural = game.join(chips(100));
+    let other = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(natural, chips(10)).unwrap();
+    game.bet(other, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),   // natural
+            card(Suit::Clubs, 9),    // other
+            card(Suit::Spades, 1),   // dealer up (Ace)
+            card(Suit::Hearts, 13),  // natural
+            card(Suit::Clubs, 7),    // other
+            card(Suit::Diamonds, 6), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+
+    // Even money is paid at once, even on a 6:5 table
+    assert_eq!(
+        game.take_even_money_or_insurance(natural).unwrap(),
+        chips(20)
+    );
+    assert_eq!(game.get_money(natural), Some(chips(110)));
+    assert_eq!(game.get_insurance_bet(natural), None);
+
+    assert_eq!(game.take_even_money_or_insurance(other).unwrap(), chips(5));
+    assert_eq!(game.get_money(other), Some(chips(85)));
+    assert_eq!(game.get_insurance_bet(other), Some(chips(5)));
+}
+
+#[test]
+fn split_hands_settle_as_separate_ledger_entries() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 12);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),   // player
+            card(Suit::Clubs, 10),   // dealer up
+            card(Suit::Diamonds, 8), // player
+            card(Suit::Spades, 7),   // dealer hole
+            card(Suit::Hearts, 10),  // split hand 0 draw (18)
+            card(Suit::Clubs, 5),    // split hand 1 draw (13)
+        ],
+    );
+
+    game.deal().unwrap();
+    game.split(player, 0).unwrap();
+    game.stand(player, 0).unwrap();
+    game.stand(player, 1).unwrap();
+    game.dealer_play().unwrap();
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.ledger.len(), 2);
+    assert_eq!(result.ledger[0].source, LedgerSource::Hand(0));
+    assert_eq!(result.ledger[0].credit, chips(20));
+    assert_eq!(result.ledger[0].net, 10);
+    assert_eq!(result.ledger[1].source, LedgerSource::Hand(1));
+    assert_eq!(result.ledger[1].credit, chips(0));
+    assert_eq!(result.ledger[1].net, -10);
+
+    let ledger_net: i64 = result.ledger.iter().map(|entry| entry.net).sum();
+    assert_eq!(ledger_net, result.players[0].net);
+    assert_eq!(game.get_money(player), Some(chips(100)));
+}
+
+#[test]
+fn natural_requires_two_unsplit_cards() {
+    let mut natural = Hand::new(chips(10));
+    natural.add_card(card(Suit::Hearts, 1));
+    natural.add_card(card(Suit::Spades, 13));
+    assert!(natural.is_natural());
+
+    let mut drawn = Hand::new(chips(10));
+    drawn.add_card(card(Suit::Hearts, 1));
+    drawn.add_card(card(Suit::Clubs, 5));
+    drawn.add_card(card(Suit::Diamonds, 5));
+    assert_eq!(drawn.value(), 21);
+    assert!(!drawn.is_natural());
+
+    let mut split_ace = Hand::from_split(card(Suit::Hearts, 1), chips(10));
+    split_ace.add_card(card(Suit::Clubs, 13));
+    assert_eq!(split_ace.value(), 21);
+    assert!(!split_ace.is_natural());
+
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 4);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),   // player
+            card(Suit::Clubs, 9),    // dealer up
+            card(Suit::Spades, 12),  // player
+            card(Suit::Diamonds, 7), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+    assert!(game.player_has_natural(player, 0));
+    assert!(!game.player_has_natural(player, 1));
+    assert!(!game.player_has_natural(player + 1, 0));
+}
+
+#[test]
+fn cancel_bet_refunds_and_skips_player() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 6);
+    let cancelled = game.join(chips(100));
+    let staying = game.join(chips(100));
+
+    assert_eq!(
+        game.cancel_bet(cancelled).unwrap_err(),
+        BetError::InvalidState
+    );
+
+    game.start_betting();
+    assert_eq!(game.cancel_bet(cancelled).unwrap_err(), BetError::NoBet);
+
+    game.bet(cancelled, chips(30)).unwrap();
+    game.bet(staying, chips(10)).unwrap();
+    assert_eq!(game.get_money(cancelled), Some(chips(70)));
+
+    assert_eq!(game.cancel_bet(cancelled).unwrap(), chips(30));
+    assert_eq!(game.get_money(cancelled), Some(chips(100)));
+    assert_eq!(game.get_bet(cancelled), None);
+
+    game.deal().unwrap();
+    assert!(game.get_hands(cancelled).is_none());
+    assert_eq!(game.get_hands(staying).unwrap().len(), 1);
+}
+
+#[test]
+fn spectator_view_hides_dealer_hole_until_revealed() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 13);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),  // player
+            card(Suit::Clubs, 9),    // dealer up
+            card(Suit::Spades, 9),   // player
+            card(Suit::Diamonds, 8), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+
+    let view = game.spectator_view();
+    assert_eq!(view.state, GameState::PlayerTurn);
+    assert_eq!(view.dealer.cards, vec![Some(card(Suit::Clubs, 9)), None]);
+    assert_eq!(view.dealer.visible_value, 9);
+    assert_eq!(view.players.len(), 1);
+    assert_eq!(view.players[0].hands[0].value(), 19);
+
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+
+    let view = game.spectator_view();
+    assert_eq!(
+        view.dealer.cards,
+        vec![Some(card(Suit::Clubs, 9)), Some(card(Suit::Diamonds, 8))]
+    );
+    assert_eq!(view.dealer.visible_value, 17);
+}
+
+#[test]
+fn fractional_insurance_payout_uses_insurance_rounding() {
+    fn insured_payout(mode: RoundingMode) -> Chips {
+        let options = GameOptions::default()
+            .with_insurance_pays(Payout::new(3, 2))
+            .with_rounding_insurance(mode);
+        let game = Game::new(options, 31);
+        let player = game.join(chips(100));
+
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 9),
+                card(Suit::Spades, 1),
+                card(Suit::Diamonds, 7),
+                card(Suit::Clubs, 13),
+            ],
+        );
+        game.deal().unwrap();
+        game.take_insurance(player).unwrap();
+        assert!(game.finish_insurance().unwrap());
+
+        game.showdown().unwrap().players[0].insurance_payout
+    }
+
+    // Insurance of 5 at 1.5:1 wins 7.5.
+    assert_eq!(insured_payout(RoundingMode::Down), chips(12));
+    assert_eq!(insured_payout(RoundingMode::Up), chips(13));
+}
+
+#[test]
+fn strict_deal_requires_every_seated_player_to_bet() {
+    fn table(require_all_bet: bool) -> (Game, u8, u8) {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_require_all_bet(require_all_bet);
+        let game = Game::new(options, 17);
+        let bettor = game.join(chips(100));
+        let sitter = game.join(chips(100));
+        game.start_betting();
+        game.bet(bettor, chips(10)).unwrap();
+        (game, bettor, sitter)
+    }
+
+    let (strict, _, _) = table(true);
+    assert_eq!(strict.deal().unwrap_err(), DealError::MissingBet);
+    assert_eq!(strict.state(), GameState::Betting);
+
+    let (relaxed, bettor, sitter) = table(false);
+    relaxed.deal().unwrap();
+    assert_eq!(relaxed.get_hands(bettor).unwrap().len(), 1);
+    assert!(relaxed.get_hands(sitter).is_none());
+}
+
+#[test]
+fn hand_is_blackjack_matches_status() {
+    let mut natural = Hand::new(chips(10));
+    natural.add_card(card(Suit::Clubs, 1));
+    natural.add_card(card(Suit::Hearts, 10));
+    assert!(natural.is_blackjack());
+    assert_eq!(natural.status(), HandStatus::Blackjack);
+
+    let mut drawn = Hand::new(chips(10));
+    drawn.add_card(card(Suit::Clubs, 7));
+    drawn.add_card(card(Suit::Hearts, 7));
+    drawn.add_card(card(Suit::Spades, 7));
+    assert_eq!(drawn.value(), 21);
+    assert!(!drawn.is_blackjack());
+    assert_eq!(drawn.status(), HandStatus::Active);
+}
+
+#[test]
+fn tournament_runs_to_completion_and_ranks_by_chips() {
+    let game = Game::new(GameOptions::default(), 2026);
+    for _ in 0..3 {
+        game.join(chips(100));
+    }
+    let mut tournament = Tournament::new(game, 5);
+
+    while !tournament.is_over() {
+        tournament
+            .play_round(chips(40), |_, hand, _| {
+                if hand.value() < 17 {
+                    PlayerDecision::Hit
+                } else {
+                    PlayerDecision::Stand
+                }
+            })
+            .unwrap();
+    }
+
+    assert_eq!(
+        tournament
+            .play_round(chips(40), |_, _, _| PlayerDecision::Stand)
+            .unwrap_err(),
+        RoundError::TournamentOver
+    );
+
+    let board = tournament.leaderboard();
+    assert_eq!(board.len(), 3);
+    assert!(board.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    for &id in tournament.eliminated() {
+        assert_eq!(tournament.game().get_money(id), None);
+    }
+
+    let leader = board[0];
+    assert_eq!(tournament.winner(), Some(leader.0));
+    assert_eq!(tournament.game().get_money(leader.0), Some(leader.1));
+}
+
+#[test]
+fn tournament_eliminates_stacks_below_the_table_minimum() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_penetration(0.0)
+        .with_bet_limits(chips(10), Chips::MAX);
+    let game = Game::new(options, 2027);
+    let short = game.join(chips(15));
+    let player = game.join(chips(100));
+    let mut tournament = Tournament::new(game, 3);
+
+    set_deck_from_draws(
+        tournament.game(),
+        &[
+            card(Suit::Hearts, 10),  // short
+            card(Suit::Clubs, 10),   // player
+            card(Suit::Spades, 10),  // dealer up
+            card(Suit::Hearts, 6),   // short: 16
+            card(Suit::Clubs, 9),    // player: 19
+            card(Suit::Diamonds, 8), // dealer: 18
+        ],
+    );
+    tournament
+        .play_round(chips(10), |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    // Five chips cannot cover the minimum bet
+    assert_eq!(tournament.eliminated(), &[short]);
+    assert_eq!(tournament.game().get_money(short), None);
+
+    set_deck_from_draws(
+        tournament.game(),
+        &[
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Diamonds, 7),
+        ],
+    );
+    tournament
+        .play_round(chips(10), |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    assert_eq!(tournament.rounds_played(), 2);
+    assert_eq!(tournament.game().get_money(player), Some(chips(120)));
+    assert_eq!(
+        tournament
+            .play_round(chips(5), |_, _, _| PlayerDecision::Stand)
+            .unwrap_err(),
+        RoundError::Bet(BetError::BelowMinimum)
+    );
+}
+
+#[test]
+fn remaining_composition_tracks_dealt_ranks() {
+    let options = GameOptions::default().with_decks(1).with_insurance(false);
+    let game = Game::new(options, 5);
+    let player = game.join(chips(100));
+
+    assert_eq!(game.remaining_composition(), [4; 13]);
+    let by_value = game.remaining_by_value();
+    assert_eq!(by_value[0], 0);
+    assert_eq!(by_value[1], 4);
+    assert_eq!(by_value[10], 16);
+
+    let dealt = [
+        card(Suit::Hearts, 1),   // player
+        card(Suit::Clubs, 9),    // dealer up
+        card(Suit::Spades, 13),  // player
+        card(Suit::Diamonds, 9), // dealer hole
+    ];
+    let mut shoe: Vec<Card> = game
+        .decks
+        .lock()
+        .iter()
+        .copied()
+        .filter(|c| !dealt.contains(c))
+        .collect();
+    shoe.extend(dealt.iter().rev());
+    *game.decks.lock() = shoe;
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+
+    let mut expected = [4; 13];
+    expected[0] -= 1; // ace
+    expected[8] -= 2; // two nines
+    expected[12] -= 1; // king
+    assert_eq!(game.remaining_composition(), expected);
+
+    let by_value = game.remaining_by_value();
+    assert_eq!(by_value[1], 3);
+    assert_eq!(by_value[9], 2);
+    assert_eq!(by_value[10], 15);
+    assert_eq!(by_value.iter().sum::<usize>(), game.cards_remaining());
+}
+
+#[test]
+fn dealer_upcard_first_gives_dealer_the_top_card() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_deal_order(DealOrder::DealerUpcardFirst);
+    let game = Game::new(options, 9);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 6),   // dealer up
+            card(Suit::Clubs, 10),   // player
+            card(Suit::Spades, 7),   // player
+            card(Suit::Diamonds, 9), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+
+    let dealer = game.get_dealer_hand();
+    assert_eq!(
+        dealer.cards(),
+        &[card(Suit::Hearts, 6), card(Suit::Diamonds, 9)]
+    );
+    assert_eq!(
+        game.get_hands(player).unwrap()[0].cards(),
+        &[card(Suit::Clubs, 10), card(Suit::Spades, 7)]
+    );
+}
+
+#[test]
+fn clear_round_keeps_previous_dealer_hand() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 14);
+    let player = game.join(chips(100));
+    assert!(game.last_dealer_hand().is_none());
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),  // player
+            card(Suit::Clubs, 10),   // dealer up
+            card(Suit::Spades, 9),   // player
+            card(Suit::Diamonds, 8), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    game.showdown().unwrap();
+    game.clear_round();
+
+    assert!(game.get_dealer_hand().is_empty());
+    assert!(!game.get_dealer_hand().is_hole_revealed());
+
+    let last = game.last_dealer_hand().unwrap();
+    assert_eq!(
+        last.cards(),
+        &[card(Suit::Clubs, 10), card(Suit::Diamonds, 8)]
+    );
+    assert!(last.is_hole_revealed());
+    assert_eq!(last.value(), 18);
+}
+
+#[test]
+fn rule_presets_set_documented_fields() {
+    let strip = GameOptions::vegas_strip();
+    assert_eq!(strip.decks, 6);
+    assert!(strip.stand_on_soft_17);
+    assert_eq!(strip.double, DoubleOption::Any);
+    assert!(strip.double_after_split);
+    assert_eq!(strip.split, 3);
+    assert!(strip.split_aces_only_once);
+    assert!(strip.split_aces_receive_one_card);
+    assert_eq!(strip.surrender, SurrenderRule::Late);
+    assert_eq!(strip.blackjack_pays, Payout::new(3, 2));
+
+    let atlantic = GameOptions::atlantic_city();
+    assert_eq!(atlantic.decks, 8);
+    assert!(atlantic.stand_on_soft_17);
+    assert_eq!(atlantic.double, DoubleOption::Any);
+    assert!(atlantic.double_after_split);
+    assert_eq!(atlantic.split, 3);
+    assert_eq!(atlantic.surrender, SurrenderRule::Late);
+    assert_eq!(atlantic.blackjack_pays, Payout::new(3, 2));
+
+    let single = GameOptions::single_deck_h17();
+    assert_eq!(single.decks, 1);
+    assert!(!single.stand_on_soft_17);
+    assert_eq!(single.double, DoubleOption::Any);
+    assert!(!single.double_after_split);
+    assert_eq!(single.split, 1);
+    assert_eq!(single.surrender, SurrenderRule::None);
+    assert_eq!(single.blackjack_pays, Payout::new(3, 2));
+    assert!(strip.dealer_peek && atlantic.dealer_peek && single.dealer_peek);
+
+    let single_s17 = GameOptions::single_deck();
+    assert_eq!(single_s17.decks, 1);
+    assert!(single_s17.stand_on_soft_17);
+    assert!(!single_s17.double_after_split);
+    assert!(single_s17.dealer_peek);
+
+    let european = GameOptions::european();
+    assert_eq!(european.decks, 6);
+    assert!(european.no_hole_card);
+    assert!(!european.dealer_peek);
+    assert!(european.stand_on_soft_17);
+    assert_eq!(european.double, DoubleOption::NineThrough11);
+    assert_eq!(european.split, 1);
+    assert_eq!(european.surrender, SurrenderRule::None);
+    assert_eq!(european.blackjack_pays, Payout::new(3, 2));
+}
+
+#[test]
+fn hand_nets_sum_to_player_net_after_split_and_double() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 16);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),   // player
+            card(Suit::Clubs, 10),   // dealer up
+            card(Suit::Diamonds, 8), // player
+            card(Suit::Spades, 9),   // dealer hole
+            card(Suit::Hearts, 3),   // split hand 0 draw (11)
+            card(Suit::Clubs, 9),    // split hand 1 draw (17)
+            card(Suit::Spades, 10),  // double on hand 0 (21)
+        ],
+    );
+    game.deal().unwrap();
+    game.split(player, 0).unwrap();
+    game.double_down(player, 0).unwrap();
+    game.stand(player, 1).unwrap();
+    game.dealer_play().unwrap();
+
+    let result = game.showdown().unwrap();
+    let player_result = &result.players[0];
+    assert_eq!(player_result.hands[0].bet, chips(20));
+    assert_eq!(player_result.hands[0].net(), 20);
+    assert_eq!(player_result.hands[1].net(), -10);
+
+    let hand_nets: i64 = player_result.hands.iter().map(bjrs::HandResult::net).sum();
+    assert_eq!(hand_nets + player_result.insurance_net(), player_result.net);
+    assert_eq!(player_result.net, 10);
+}
+
+#[test]
+fn deal_refuses_when_reserve_cannot_be_kept() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_reserve_cards(3);
+    let game = Game::new(options, 18);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 6];
+    assert_eq!(game.deal().unwrap_err(), DealError::NotEnoughCards);
+    assert_eq!(game.cards_remaining(), 6);
+
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 7];
+    game.deal().unwrap();
+    assert_eq!(game.cards_remaining(), 3);
+}
+
+#[test]
+fn simulate_aggregates_flat_bet_rounds() {
+    let options = GameOptions::default().with_penetration(0.0);
+    let game = Game::new(options, 19);
+    let player = game.join(chips(100));
+
+    set_deck_from_draws(
+        &game,
+        &[
+            // Round 1: 19 beats 17.
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 7),
+            // Round 2: natural against 18.
+            card(Suit::Hearts, 1),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 13),
+            card(Suit::Diamonds, 8),
+            // Round 3: 16 loses to 19.
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 9),
+            // Round 4: 18 pushes 18.
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 8),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+
+    let report = game
+        .simulate(4, chips(10), |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    assert_eq!(report.rounds, 4);
+    assert_eq!(report.hands, 4);
+    assert_eq!(report.total_wagered, chips(40));
+    assert_eq!(report.total_returned, chips(55));
+    assert_eq!(
+        (report.wins, report.losses, report.pushes, report.blackjacks),
+        (1, 1, 1, 1)
+    );
+    assert_eq!(report.net(), 15);
+    assert_eq!(report.edge(), 0.375);
+    assert_eq!(game.get_money(player), Some(chips(115)));
+
+    // The shoe is empty, so the next round cannot be dealt.
+    assert!(matches!(
+        game.simulate(1, chips(10), |_, _, _| PlayerDecision::Stand),
+        Err(RoundError::Deal(DealError::NotEnoughCards))
+    ));
+}
+
+#[test]
+fn simulate_only_seats_players_who_can_cover_the_stake() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_blackjack_switch(true)
+        .with_bet_limits(chips(10), Chips::MAX);
+    let game = Game::new(options, 19);
+    let short = game.join(chips(15));
+    game.join(chips(100));
+
+    assert!(matches!(
+        game.simulate(1, chips(5), |_, _, _| PlayerDecision::Stand),
+        Err(RoundError::Bet(BetError::BelowMinimum))
+    ));
+
+    // Switch takes 20, so the short stack sits out
+    let report = game
+        .simulate(1, chips(10), |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    assert_eq!(report.rounds, 1);
+    assert_eq!(report.hands, 2);
+    assert_eq!(report.total_wagered, chips(20));
+    assert_eq!(game.get_money(short), Some(chips(15)));
+}
+
+#[test]
+fn revise_insurance_refunds_when_switching_to_decline() {
+    let game = Game::new(GameOptions::default(), 20);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(20)).unwrap();
+    assert_eq!(
+        game.revise_insurance(player, false).unwrap_err(),
+        InsuranceError::InvalidState
+    );
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Clubs, 8),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(game.take_insurance(player).unwrap(), chips(10));
+    assert_eq!(game.get_money(player), Some(chips(70)));
+
+    assert_eq!(game.revise_insurance(player, false).unwrap(), chips(10));
+    assert_eq!(game.get_money(player), Some(chips(80)));
+    assert_eq!(game.get_insurance_bet(player), None);
+    assert_eq!(
+        game.take_insurance(player).unwrap_err(),
+        InsuranceError::AlreadyDecided
+    );
+
+    assert_eq!(game.revise_insurance(player, true).unwrap(), chips(10));
+    assert_eq!(game.get_money(player), Some(chips(70)));
+    assert!(game.all_insurance_decided());
+    assert!(!game.finish_insurance().unwrap());
+}
+
+#[test]
+fn deal_from_gives_tables_identical_rounds() {
+    let cards = [
+        card(Suit::Hearts, 10),
+        card(Suit::Clubs, 9),
+        card(Suit::Spades, 4),
+        card(Suit::Diamonds, 8),
+        card(Suit::Hearts, 5),
+        card(Suit::Clubs, 3),
+    ];
+
+    let tables: Vec<(Game, u8)> = [21, 22]
+        .into_iter()
+        .map(|seed| {
+            let game = Game::new(GameOptions::default().with_insurance(false), seed);
+            let player = game.join(chips(100));
+            (game, player)
+        })
+        .collect();
+
+    let mut dealt = Vec::new();
+    for (game, player) in &tables {
+        let shoe_before = game.decks.lock().clone();
+
+        game.start_betting();
+        game.bet(*player, chips(10)).unwrap();
+        game.deal_from(&cards).unwrap();
+        assert_eq!(game.cards_remaining(), 2);
+
+        game.hit(*player, 0).unwrap();
+        game.stand(*player, 0).unwrap();
+        game.dealer_play().unwrap();
+        dealt.push((
+            game.get_hands(*player).unwrap()[0].cards().to_vec(),
+            game.get_dealer_hand().cards().to_vec(),
+        ));
+        game.showdown().unwrap();
+        game.clear_round();
+
+        assert_eq!(*game.decks.lock(), shoe_before);
+        assert_eq!(game.cards_remaining(), shoe_before.len());
+    }
+
+    assert_eq!(dealt[0], dealt[1]);
+    assert_eq!(
+        dealt[0].0,
+        vec![
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 4),
+            card(Suit::Hearts, 5)
+        ]
+    );
+    assert_eq!(
+        dealt[0].1,
+        vec![card(Suit::Clubs, 9), card(Suit::Diamonds, 8)]
+    );
+}
+
+#[test]
+fn total_at_risk_sums_doubled_hand_and_insurance() {
+    let game = Game::new(GameOptions::default(), 23);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(20)).unwrap();
+    assert_eq!(game.total_at_risk(player), chips(20));
+    game.place_side_bet(player, SideBetKind::PerfectPairs, chips(5))
+        .unwrap();
+    game.place_side_bet(player, SideBetKind::Buster, chips(5))
+        .unwrap();
+    assert_eq!(game.total_at_risk(player), chips(30));
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 6),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 5),
+            card(Suit::Clubs, 7),
+            card(Suit::Clubs, 10),
+        ],
+    );
+    game.deal().unwrap();
+    // Perfect Pairs was decided by the deal; Buster waits for the dealer
+    assert_eq!(game.total_at_risk(player), chips(25));
+
+    game.take_insurance(player).unwrap();
+    assert_eq!(game.total_at_risk(player), chips(35));
+    assert!(!game.finish_insurance().unwrap());
+
+    game.double_down(player, 0).unwrap();
+    let hand_bet = game.get_hands(player).unwrap()[0].bet();
+    assert_eq!(hand_bet, chips(40));
+    assert_eq!(
+        game.total_at_risk(player),
+        hand_bet + game.get_insurance_bet(player).unwrap() + chips(5)
+    );
+    assert_eq!(game.total_at_risk(player), chips(55));
+    assert_eq!(game.total_at_risk(player + 1), chips(0));
+}
+
+#[test]
+fn next_round_opens_betting_with_players_kept() {
+    let options = GameOptions::default().with_insurance(false);
+    let empty = Game::new(options.clone(), 24);
+    assert_eq!(empty.next_round().unwrap_err(), RoundError::NoPlayers);
+    assert_eq!(empty.state(), GameState::WaitingForPlayers);
+
+    let game = Game::new(options, 24);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    game.deal().unwrap();
+
+    game.next_round().unwrap();
+    assert_eq!(game.state(), GameState::Betting);
+    assert_eq!(game.player_count(), 2);
+    assert_eq!(game.get_bet(first), None);
+    assert_eq!(game.get_bet(second), None);
+    assert!(game.get_hands(first).is_none());
+    assert!(game.get_dealer_hand().is_empty());
+
+    game.bet(first, chips(10)).unwrap();
+    game.deal().unwrap();
+}
+
+#[test]
+fn compare_blackjack_resolves_busts_naturals_and_ties() {
+    fn player(ranks: &[u8]) -> Hand {
+        let mut hand = Hand::new(chips(10));
+        for &rank in ranks {
+            hand.add_card(card(Suit::Hearts, rank));
+        }
+        hand
+    }
+    fn dealer(ranks: &[u8]) -> DealerHand {
+        let cards: Vec<Card> = ranks.iter().map(|&rank| card(Suit::Clubs, rank)).collect();
+        DealerHand::from_cards(&cards, true)
+    }
+
+    // A busted player loses even when the dealer busts too.
+    let bust = player(&[10, 6, 9]);
+    assert_eq!(bust.status(), HandStatus::Bust);
+    assert_eq!(
+        compare_blackjack(&bust, &dealer(&[10, 6, 8]), Tie::Player),
+        HandOutcome::Lose
+    );
+    assert_eq!(
+        compare_blackjack(&player(&[10, 8]), &dealer(&[10, 6, 8]), Tie::Push),
+        HandOutcome::Win
+    );
+
+    // A natural beats a drawn 21 on either side.
+    assert_eq!(
+        compare_blackjack(&player(&[1, 13]), &dealer(&[7, 7, 7]), Tie::Push),
+        HandOutcome::Blackjack
+    );
+    assert_eq!(
+        compare_blackjack(&player(&[7, 7, 7]), &dealer(&[1, 12]), Tie::Push),
+        HandOutcome::Lose
+    );
+    let mut split_21 = Hand::from_split(card(Suit::Hearts, 1), chips(10));
+    split_21.add_card(card(Suit::Spades, 10));
+    assert_eq!(
+        compare_blackjack(&split_21, &dealer(&[1, 12]), Tie::Player),
+        HandOutcome::Lose
+    );
+
+    // Equal totals and natural against natural follow the tie rule.
+    let eighteen = player(&[10, 8]);
+    assert_eq!(
+        compare_blackjack(&eighteen, &dealer(&[9, 9]), Tie::Push),
+        HandOutcome::Push
+    );
+    assert_eq!(
+        compare_blackjack(&eighteen, &dealer(&[9, 9]), Tie::Dealer),
+        HandOutcome::Lose
+    );
+    assert_eq!(
+        compare_blackjack(&player(&[1, 10]), &dealer(&[1, 11]), Tie::Push),
+        HandOutcome::Push
+    );
+    assert_eq!(eighteen.beats(22), core::cmp::Ordering::Greater);
+    assert_eq!(bust.beats(17), core::cmp::Ordering::Less);
+}
+
+#[test]
+fn double_exposure_shows_hole_and_dealer_wins_ties() {
+    let options = GameOptions::default().with_double_exposure(true);
+    let game = Game::new(options, 25);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 13),
+            card(Suit::Clubs, 9),
+        ],
+    );
+    game.deal().unwrap();
+
+    // Both dealer cards are visible and insurance is skipped.
+    assert_eq!(game.state(), GameState::PlayerTurn);
+    let dealer = game.get_dealer_hand();
+    assert!(dealer.is_hole_revealed());
+    assert_eq!(dealer.visible_value(), 20);
+    assert_eq!(
+        game.spectator_view().dealer.cards,
+        vec![Some(card(Suit::Spades, 1)), Some(card(Suit::Clubs, 9))]
+    );
+
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].player_value, 20);
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(game.get_money(player), Some(chips(90)));
+}
+
+#[test]
+fn lowering_penetration_triggers_reshuffle_on_next_check() {
+    let options = GameOptions::default().with_decks(1).with_penetration(0.9);
+    let game = Game::new(options, 26);
+    game.decks.lock().truncate(DECK_SIZE / 2);
+
+    assert!(!game.needs_reshuffle());
+    assert_eq!(game.check_and_reshuffle(), Ok(false));
+
+    assert_eq!(
+        game.set_penetration(1.5).unwrap_err(),
+        OptionsError::InvalidPenetration
+    );
+    assert_eq!(game.options().penetration, 0.9);
+
+    game.set_penetration(0.4).unwrap();
+    assert_eq!(game.options().penetration, 0.4);
+    assert_eq!(game.check_and_reshuffle(), Ok(true));
+    assert_eq!(game.cards_remaining(), DECK_SIZE);
+}
+
+#[test]
+fn update_options_changes_payout_between_rounds() {
+    fn natural_round(game: &Game, player: u8) -> Chips {
+        set_deck_from_draws(
+            game,
+            &[
+                card(Suit::Hearts, 1),
+                card(Suit::Clubs, 10),
+                card(Suit::Spades, 13),
+                card(Suit::Diamonds, 8),
+            ],
+        );
+        let result = game
+            .play_round(&[(player, chips(10))], |_, _, _| PlayerDecision::Stand)
+            .unwrap();
+        result.players[0].hands[0].payout
+    }
+
+    let options = GameOptions::default().with_penetration(0.0);
+    let game = Game::new(options, 27);
+    let player = game.join(chips(100));
+
+    assert_eq!(natural_round(&game, player), chips(25));
+
+    game.update_options(|options| options.blackjack_pays = Payout::new(6, 5))
+        .unwrap();
+    assert_eq!(natural_round(&game, player), chips(22));
+
+    game.reshuffle().unwrap();
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(
+        game.update_options(|options| options.blackjack_pays = Payout::new(3, 2))
+            .unwrap_err(),
+        OptionsError::InvalidState
+    );
+    assert_eq!(game.options().blackjack_pays, Payout::new(6, 5));
+    game.clear_round();
+
+    assert_eq!(
+        game.update_options(|options| options.penetration = -1.0)
+            .unwrap_err(),
+        OptionsError::InvalidPenetration
+    );
+    game.update_options(|options| options.decks = 3).unwrap();
+    assert_eq!(game.cards_remaining(), 3 * DECK_SIZE);
+}
+
+#[test]
+fn hit_detailed_reports_bust_and_next_player() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 28);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),  // first
+            card(Suit::Clubs, 5),    // second
+            card(Suit::Spades, 9),   // dealer up
+            card(Suit::Hearts, 2),   // first
+            card(Suit::Clubs, 6),    // second
+            card(Suit::Spades, 8),   // dealer hole
+            card(Suit::Diamonds, 3), // first hits to 15
+            card(Suit::Diamonds, 9), // first busts
+        ],
+    );
+    game.deal().unwrap();
+
+    let outcome = game.hit_detailed(first, 0).unwrap();
+    assert_eq!(outcome.card, card(Suit::Diamonds, 3));
+    assert_eq!(outcome.hand_status, HandStatus::Active);
+    assert!(!outcome.turn_advanced);
+    assert_eq!(outcome.new_current, Some((first, 0)));
+
+    let outcome = game.hit_detailed(first, 0).unwrap();
+    assert_eq!(outcome.hand_status, HandStatus::Bust);
+    assert!(outcome.turn_advanced);
+    assert_eq!(outcome.new_current, Some((second, 0)));
+    assert_eq!(game.current_player(), Some(second));
+}
+
+#[test]
+fn dealer_should_hit_follows_soft_17_rule() {
+    fn soft_17_game(stand_on_soft_17: bool) -> Game {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_stand_on_soft_17(stand_on_soft_17);
+        let game = Game::new(options, 29);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 10),
+                card(Suit::Spades, 1),
+                card(Suit::Diamonds, 9),
+                card(Suit::Clubs, 6),
+            ],
+        );
+        game.deal().unwrap();
+        game.stand(player, 0).unwrap();
+        game
+    }
+
+    let s17 = soft_17_game(true);
+    assert!(!s17.dealer_should_hit());
+    assert!(s17.dealer_play().unwrap().is_empty());
+
+    let h17 = soft_17_game(false);
+    assert!(h17.dealer_should_hit());
+    set_deck_from_draws(&h17, &[card(Suit::Hearts, 2)]);
+    assert_eq!(h17.dealer_play().unwrap(), vec![card(Suit::Hearts, 2)]);
+    assert!(!h17.dealer_should_hit());
+
+    let mut hard = DealerHand::new();
+    hard.add_card(card(Suit::Clubs, 10));
+    hard.add_card(card(Suit::Clubs, 7));
+    assert!(!hard.should_hit(false));
+    hard.clear();
+    hard.add_card(card(Suit::Clubs, 10));
+    hard.add_card(card(Suit::Clubs, 6));
+    assert!(hard.should_hit(true));
+}
+
+#[test]
+fn draw_card_burns_to_discards_between_rounds() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 30);
+    let player = game.join(chips(100));
+    let total = game.cards_remaining();
+
+    let burned = game.draw_card().unwrap();
+    assert_eq!(game.cards_remaining(), total - 1);
+    assert_eq!(game.discards(), vec![burned]);
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(game.draw_card(), None);
+    assert_eq!(game.cards_remaining(), total - 5);
+
+    game.clear_round();
+    assert_eq!(game.discards().len(), 5);
+    assert_eq!(game.discards()[0], burned);
+
+    game.reshuffle().unwrap();
+    assert!(game.discards().is_empty());
+}
+
+#[test]
+fn no_hole_card_dealer_stays_concealed_when_all_hands_bust() {
+    fn bust_round(no_hole_card: bool) -> Game {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_no_hole_card(no_hole_card);
+        let game = Game::new(options, 32);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        let mut draws = vec![
+            card(Suit::Hearts, 8),
+            card(Suit::Spades, 10),
+            card(Suit::Diamonds, 8),
+            card(Suit::Clubs, 6),   // dealer hole, not dealt without one
+            card(Suit::Clubs, 6),   // split hand 0 draws
+            card(Suit::Hearts, 7),  // split hand 1 draws
+            card(Suit::Spades, 13), // hand 0 busts
+            card(Suit::Hearts, 12), // hand 1 busts
+        ];
+        if no_hole_card {
+            draws.remove(3);
+        }
+        set_deck_from_draws(&game, &draws);
+        game.deal().unwrap();
+        game.split(player, 0).unwrap();
+        game.hit(player, 0).unwrap();
+        game.hit(player, 1).unwrap();
+        assert_eq!(game.state(), GameState::DealerTurn);
+        assert!(game.dealer_play().unwrap().is_empty());
+        game.showdown().unwrap();
+        game
+    }
+
+    let enhc = bust_round(true);
+    assert_eq!(enhc.state(), GameState::RoundOver);
+    let dealer = enhc.get_dealer_hand();
+    assert_eq!(dealer.len(), 1);
+    assert!(!dealer.is_hole_revealed());
+    assert_eq!(dealer.visible_value(), 10);
+
+    assert!(bust_round(false).get_dealer_hand().is_hole_revealed());
+}
+
+#[test]
+fn settle_external_reports_nets_without_crediting_money() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 33);
+    let winner = game.join(chips(100));
+    let loser = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(winner, chips(10)).unwrap();
+    game.bet(loser, chips(20)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),  // winner
+            card(Suit::Clubs, 10),   // loser
+            card(Suit::Spades, 10),  // dealer up
+            card(Suit::Hearts, 9),   // winner: 19
+            card(Suit::Clubs, 6),    // loser: 16
+            card(Suit::Diamonds, 8), // dealer: 18
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(winner, 0).unwrap();
+    game.stand(loser, 0).unwrap();
+    game.dealer_play().unwrap();
+
+    let mut deltas = Vec::new();
+    let result = game
+        .settle_external(|player_id, net| deltas.push((player_id, net)))
+        .unwrap();
+
+    assert_eq!(deltas, vec![(winner, 10), (loser, -20)]);
+    let nets: Vec<(u8, i64)> = result
+        .players
+        .iter()
+        .map(|player| (player.player_id, player.net))
+        .collect();
+    assert_eq!(deltas, nets);
+    assert_eq!(game.get_money(winner), Some(chips(90)));
+    assert_eq!(game.get_money(loser), Some(chips(80)));
+}
+
+#[test]
+fn settle_external_settles_the_round_and_leaves_out_prepaid_naturals() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_immediate_blackjack_payout(true);
+    let game = Game::new(options, 33);
+    let natural = game.join(chips(100));
+    let winner = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(natural, chips(10)).unwrap();
+    game.bet(winner, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),   // natural
+            card(Suit::Clubs, 10),   // winner
+            card(Suit::Spades, 9),   // dealer up
+            card(Suit::Hearts, 13),  // natural: blackjack
+            card(Suit::Clubs, 9),    // winner: 19
+            card(Suit::Diamonds, 9), // dealer: 18
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.get_money(natural), Some(chips(115)));
+    game.stand(winner, 0).unwrap();
+    game.dealer_play().unwrap();
+
+    let mut deltas = Vec::new();
+    let result = game
+        .settle_external(|player_id, net| deltas.push((player_id, net)))
+        .unwrap();
+    assert_eq!(result.players[0].net, 15);
+    // The blackjack was already paid at the deal
+    assert_eq!(deltas, vec![(natural, 0), (winner, 10)]);
+
+    game.showdown().unwrap();
+    assert_eq!(game.get_money(natural), Some(chips(115)));
+    assert_eq!(game.get_money(winner), Some(chips(90)));
+}
+
+#[test]
+fn standard_deck_has_each_card_once() {
+    assert_eq!(Suit::iter().collect::<Vec<_>>(), Suit::ALL.to_vec());
+
+    let deck = standard_deck();
+    assert_eq!(deck.len(), DECK_SIZE);
+    for suit in Suit::iter() {
+        for rank in 1..=13 {
+            let count = deck
+                .iter()
+                .filter(|&&dealt| dealt == card(suit, rank))
+                .count();
+            assert_eq!(count, 1, "{suit:?} {rank}");
+        }
+    }
+}
+
+#[test]
+fn surrender_only_allowed_on_opening_cards() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_surrender(SurrenderRule::Late);
+    let game = Game::new(options, 34);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    assert!(!game.surrender_allowed_now(player, 0));
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 5),
+            card(Suit::Spades, 10),
+            card(Suit::Diamonds, 4),
+            card(Suit::Clubs, 8),
+            card(Suit::Clubs, 2),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert!(game.surrender_allowed_now(player, 0));
+    assert!(!game.surrender_allowed_now(player, 1));
+    assert!(!game.surrender_allowed_now(player + 1, 0));
+
+    game.hit(player, 0).unwrap();
+    assert!(!game.surrender_allowed_now(player, 0));
+    assert_eq!(
+        game.surrender(player, 0).unwrap_err(),
+        ActionError::CannotSurrender
+    );
+}
+
+#[test]
+fn active_hands_remaining_counts_split_hands() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 35);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    assert_eq!(game.active_hands_remaining(), 0);
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),
+            card(Suit::Spades, 10),
+            card(Suit::Diamonds, 8),
+            card(Suit::Clubs, 7),
+            card(Suit::Clubs, 3),
+            card(Suit::Hearts, 2),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.active_hands_remaining(), 1);
+
+    game.split(player, 0).unwrap();
+    assert_eq!(game.active_hands_remaining(), 2);
+
+    game.stand(player, 0).unwrap();
+    assert_eq!(game.active_hands_remaining(), 1);
+
+    game.stand(player, 1).unwrap();
+    assert_eq!(game.active_hands_remaining(), 0);
+}
+
+#[test]
+fn reseeding_for_a_round_reproduces_its_shoe() {
+    let game = Game::new_with_round_seeding(GameOptions::default(), 36);
+    let round_zero = game.decks.lock().clone();
+
+    game.reseed_for_round(57).unwrap();
+    let first = game.decks.lock().clone();
+    game.draw_card().unwrap();
+    game.reseed_for_round(57).unwrap();
+    assert_eq!(*game.decks.lock(), first);
+    assert_ne!(first, round_zero);
+
+    let other = Game::new_with_round_seeding(GameOptions::default(), 36);
+    assert_eq!(*other.decks.lock(), round_zero);
+    other.reseed_for_round(57).unwrap();
+    assert_eq!(*other.decks.lock(), first);
+
+    game.reseed_for_round(58).unwrap();
+    assert_ne!(*game.decks.lock(), first);
+}
+
+#[test]
+fn hand_double_bet_only_doubles_once() {
+    let mut hand = Hand::new(chips(10));
+    assert!(!hand.is_doubled());
+
+    assert!(hand.double_bet());
+    assert!(hand.is_doubled());
+    assert_eq!(hand.bet(), chips(20));
+
+    assert!(!hand.double_bet());
+    assert!(hand.is_doubled());
+    assert_eq!(hand.bet(), chips(20));
+}
+
+#[test]
+fn cards_dealt_this_round_counts_deal_and_hits() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 37);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.draw_card().unwrap();
+    assert_eq!(game.cards_dealt_this_round(), 0);
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 2),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Hearts, 3),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 8),
+            card(Suit::Diamonds, 2),
+            card(Suit::Diamonds, 3),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.cards_dealt_this_round(), 6);
+
+    game.hit(first, 0).unwrap();
+    assert_eq!(game.cards_dealt_this_round(), 7);
+    game.hit(first, 0).unwrap();
+    assert_eq!(game.cards_dealt_this_round(), 8);
+}
+
+#[test]
+fn no_hole_card_dealer_takes_second_card_when_playing() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_no_hole_card(true);
+    let game = Game::new(options, 38);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    *game.decks.lock() = vec![card(Suit::Hearts, 2); 2];
+    assert_eq!(game.deal().unwrap_err(), DealError::NotEnoughCards);
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 9),
+            card(Suit::Clubs, 8),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.get_dealer_hand().len(), 1);
+    assert_eq!(game.cards_remaining(), 1);
+
+    game.stand(player, 0).unwrap();
+    assert_eq!(game.dealer_play().unwrap(), vec![card(Suit::Clubs, 8)]);
+    let dealer = game.get_dealer_hand();
+    assert_eq!(dealer.len(), 2);
+    assert!(dealer.is_hole_revealed());
+    assert_eq!(dealer.value(), 17);
+}
+
+#[test]
+fn pending_hand_index_only_for_current_player() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 39);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 8),
+            card(Suit::Clubs, 7),
+            card(Suit::Spades, 8),
+            card(Suit::Hearts, 2),
+            card(Suit::Hearts, 3),
+        ],
+    );
+    assert_eq!(game.pending_hand_index(first), None);
+    game.deal().unwrap();
+
+    assert_eq!(game.pending_hand_index(first), Some(0));
+    assert_eq!(game.pending_hand_index(second), None);
+
+    game.split(first, 0).unwrap();
+    game.stand(first, 0).unwrap();
+    assert_eq!(game.pending_hand_index(first), Some(1));
+    assert_eq!(game.pending_hand_index(second), None);
+
+    game.stand(first, 1).unwrap();
+    assert_eq!(game.pending_hand_index(first), None);
+    assert_eq!(game.pending_hand_index(second), Some(0));
+}
+
+#[test]
+fn immediate_blackjack_payout_pays_natural_at_deal() {
+    let options = GameOptions::default().with_immediate_blackjack_payout(true);
+    let game = Game::new(options, 40);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 13),
+            card(Suit::Clubs, 10),
+            card(Suit::Clubs, 5),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(game.get_money(player), Some(chips(115)));
+    assert_eq!(game.immediate_payout(player), Some(chips(25)));
+    assert_eq!(game.current_player(), None);
+    assert_eq!(game.state(), GameState::DealerTurn);
+
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    let hand = result.players[0].hands[0];
+    assert_eq!(hand.outcome, HandOutcome::Blackjack);
+    assert_eq!(hand.payout, chips(25));
+    assert_eq!(result.players[0].net, 15);
+    assert_eq!(result.ledger[0].credit, chips(25));
+    assert_eq!(game.get_money(player), Some(chips(115)));
+
+    game.clear_round();
+    assert_eq!(game.immediate_payout(player), None);
+}
+
+#[test]
+fn finishing_insurance_moves_to_dealer_when_no_hand_can_act() {
+    let game = Game::new(GameOptions::default(), 40);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 13),
+            card(Suit::Clubs, 7),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.state(), GameState::Insurance);
+
+    game.decline_insurance(player).unwrap();
+    assert_eq!(game.finish_insurance(), Ok(false));
+    assert_eq!(game.state(), GameState::DealerTurn);
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Blackjack);
+    assert_eq!(game.get_money(player), Some(chips(115)));
+}
+
+#[test]
+fn settle_player_credits_only_that_player() {
+    let game = Game::new(GameOptions::default(), 41);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 10),
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 7),
+            card(Suit::Spades, 8),
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(first, 0).unwrap();
+    game.stand(second, 0).unwrap();
+
+    assert_eq!(
+        game.settle_player(first).unwrap_err(),
+        ShowdownError::InvalidState
+    );
+    game.dealer_play().unwrap();
+
+    let settled = game.settle_player(first).unwrap();
+    assert_eq!(settled.player_id, first);
+    assert_eq!(settled.net, 10);
+    assert_eq!(game.get_money(first), Some(chips(110)));
+    assert_eq!(game.get_money(second), Some(chips(90)));
+    assert_eq!(
+        game.settle_player(first).unwrap_err(),
+        ShowdownError::AlreadySettled
+    );
+    assert_eq!(
+        game.settle_player(99).unwrap_err(),
+        ShowdownError::PlayerNotFound
+    );
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players.len(), 2);
+    assert_eq!(game.get_money(first), Some(chips(110)));
+    assert_eq!(game.get_money(second), Some(chips(90)));
+}
+
+#[test]
+fn dealer_hand_from_cards_and_upcard_predicates() {
+    let dealer = DealerHand::from_cards(&[card(Suit::Hearts, 1), card(Suit::Clubs, 6)], false);
+    assert_eq!(dealer.len(), 2);
+    assert!(!dealer.is_hole_revealed());
+    assert!(dealer.has_ace_upcard());
+    assert_eq!(dealer.upcard_value(), 11);
+    assert_eq!(dealer.visible_value(), 11);
+    assert_eq!(dealer.value(), 17);
+
+    let dealer = DealerHand::from_cards(&[card(Suit::Spades, 12), card(Suit::Clubs, 1)], true);
+    assert!(!dealer.has_ace_upcard());
+    assert_eq!(dealer.upcard_value(), 10);
+    assert_eq!(dealer.visible_value(), 21);
+    assert!(dealer.is_blackjack());
+
+    let empty = DealerHand::from_cards(&[], false);
+    assert!(!empty.has_ace_upcard());
+    assert_eq!(empty.upcard_value(), 0);
+}
+
+#[test]
+fn take_insurance_for_insures_listed_players_only() {
+    let game = Game::new(GameOptions::default(), 43);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    let third = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    game.bet(third, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 8),
+            card(Suit::Spades, 7),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 9),
+            card(Suit::Clubs, 6),
+        ],
+    );
+    game.deal().unwrap();
+    assert!(game.is_insurance_offered());
+
+    let results = game.take_insurance_for(&[first, second, 99]);
+    assert_eq!(
+        results,
+        vec![Ok(chips(5)), Ok(chips(10)), Err(InsuranceError::NoBet)]
+    );
+    assert_eq!(game.get_insurance_bet(first), Some(chips(5)));
+    assert_eq!(game.get_insurance_bet(second), Some(chips(10)));
+    assert_eq!(game.get_insurance_bet(third), None);
+    assert!(!game.all_insurance_decided());
+
+    assert_eq!(
+        game.take_insurance_for(&[first]),
+        vec![Err(InsuranceError::AlreadyDecided)]
+    );
+    game.decline_insurance(third).unwrap();
+    assert!(game.all_insurance_decided());
+}
+
+#[test]
+fn actions_rejected_after_round_is_over() {
+    let game = Game::new(
+        GameOptions::default().with_surrender(SurrenderRule::Late),
+        44,
+    );
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    assert_eq!(*game.state.lock(), GameState::RoundOver);
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].net, 10);
+    assert_eq!(game.get_money(player), Some(chips(110)));
+
+    assert_eq!(game.hit(player, 0), Err(ActionError::InvalidState));
+    assert_eq!(game.stand(player, 0), Err(ActionError::InvalidState));
+    assert_eq!(game.double_down(player, 0), Err(ActionError::InvalidState));
+    assert_eq!(game.split(player, 0), Err(ActionError::InvalidState));
+    assert_eq!(game.surrender(player, 0), Err(ActionError::InvalidState));
+    assert_eq!(game.get_hands(player).unwrap()[0].len(), 2);
+
+    // A repeated showdown reports the same results without paying again.
+    let again = game.showdown().unwrap();
+    assert_eq!(again.players[0].net, 10);
+    assert_eq!(game.get_money(player), Some(chips(110)));
+}
+
+#[test]
+fn standings_sort_by_money_then_player_id() {
+    let game = Game::new(GameOptions::default(), 45);
+    let first = game.join(chips(50));
+    let second = game.join(chips(200));
+    let third = game.join(chips(50));
+    let fourth = game.join(chips(120));
+
+    assert_eq!(
+        game.standings(),
+        vec![
+            (second, chips(200)),
+            (fourth, chips(120)),
+            (first, chips(50)),
+            (third, chips(50))
+        ]
+    );
+
+    game.leave(fourth);
+    assert_eq!(
+        game.standings(),
+        vec![(second, chips(200)), (first, chips(50)), (third, chips(50))]
+    );
+}
+
+#[test]
+fn split_deny_reason_explains_unsplittable_hands() {
+    fn deal_pair(options: GameOptions, first: u8, second: u8) -> (Game, u8) {
+        let game = Game::new(options, 46);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, first),
+                card(Suit::Clubs, 9),
+                card(Suit::Spades, second),
+                card(Suit::Diamonds, 8),
+                card(Suit::Hearts, 5),
+                card(Suit::Clubs, 4),
+            ],
+        );
+        game.deal().unwrap();
+        (game, player)
+    }
+
+    let (game, player) = deal_pair(GameOptions::default(), 7, 9);
+    assert_eq!(
+        game.split_deny_reason(player, 0),
+        Some(SplitDenyReason::NotAPair)
+    );
+    assert_eq!(game.split(player, 0), Err(ActionError::CannotSplit));
+
+    let (game, player) = deal_pair(GameOptions::default(), 13, 12);
+    assert_eq!(
+        game.split_deny_reason(player, 0),
+        Some(SplitDenyReason::UnlikeTensDisallowed)
+    );
+    assert_eq!(game.split(player, 0), Err(ActionError::CannotSplit));
+
+    let (game, player) = deal_pair(GameOptions::default().with_split_any_ten(true), 13, 12);
+    assert_eq!(game.split_deny_reason(player, 0), None);
+    game.split(player, 0).unwrap();
+    assert_eq!(game.get_hands(player).unwrap().len(), 2);
+
+    let (game, player) = deal_pair(GameOptions::default().with_split(0), 8, 8);
+    assert_eq!(
+        game.split_deny_reason(player, 0),
+        Some(SplitDenyReason::MaxSplitsReached)
+    );
+}
+
+#[test]
+fn drawn_twenty_one_loses_to_dealer_blackjack() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 47);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 5),
+            card(Suit::Clubs, 13),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 1),
+            card(Suit::Hearts, 10),
+        ],
+    );
+    game.deal().unwrap();
+    game.hit(player, 0).unwrap();
+    game.stand(player, 0).unwrap();
+
+    let hand = game.get_hands(player).unwrap()[0].clone();
+    assert_eq!(hand.value(), 21);
+    assert!(!hand.is_natural());
+    assert!(!hand.is_blackjack());
+
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert!(result.dealer_blackjack);
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(game.get_money(player), Some(chips(90)));
+
+    // Blackjack status alone does not make a drawn 21 a natural.
+    let mut marked = hand;
+    marked.set_status(HandStatus::Blackjack);
+    assert!(!marked.is_blackjack());
+    let dealer = DealerHand::from_cards(&[card(Suit::Clubs, 13), card(Suit::Clubs, 1)], true);
+    assert_eq!(
+        compare_blackjack(&marked, &dealer, Tie::Push),
+        HandOutcome::Lose
+    );
+}
+
+#[test]
+fn auto_resolve_insurance_skips_insurance_state() {
+    let options = GameOptions::default().with_auto_resolve_insurance(true);
+    let game = Game::new(options, 48);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Clubs, 13),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(*game.state.lock(), GameState::RoundOver);
+    assert!(game.all_insurance_decided());
+    assert_eq!(game.get_insurance_bet(player), None);
+    let result = game.showdown().unwrap();
+    assert!(result.dealer_blackjack);
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(game.get_money(player), Some(chips(90)));
+
+    game.clear_round();
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Clubs, 8),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(*game.state.lock(), GameState::PlayerTurn);
+    assert_eq!(game.current_player(), Some(player));
+}
+
+#[test]
+fn cards_sort_by_value_then_rank_then_suit() {
+    let mut cards = vec![
+        card(Suit::Spades, 1),
+        card(Suit::Hearts, 12),
+        card(Suit::Clubs, 5),
+        card(Suit::Diamonds, 10),
+        card(Suit::Hearts, 1),
+        card(Suit::Spades, 13),
+        card(Suit::Hearts, 10),
+        card(Suit::Clubs, 9),
+    ];
+    cards.sort();
+
+    assert_eq!(
+        cards,
+        vec![
+            card(Suit::Clubs, 5),
+            card(Suit::Clubs, 9),
+            card(Suit::Hearts, 10),
+            card(Suit::Diamonds, 10),
+            card(Suit::Hearts, 12),
+            card(Suit::Spades, 13),
+            card(Suit::Hearts, 1),
+            card(Suit::Spades, 1),
+        ]
+    );
+    assert_eq!(card(Suit::Hearts, 1).value(), 11);
+    assert_eq!(card(Suit::Clubs, 11).value(), 10);
+}
+
+#[test]
+fn deal_rejects_duplicate_or_stale_seats() {
+    let game = Game::new(GameOptions::default(), 49);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    game.players.lock().push(first);
+    let remaining = game.cards_remaining();
+
+    assert_eq!(game.deal(), Err(DealError::InconsistentState));
+    assert_eq!(*game.state.lock(), GameState::Betting);
+    assert_eq!(game.cards_remaining(), remaining);
+    assert!(game.get_hands(first).is_none());
+
+    // A bet left behind by a player who is no longer seated.
+    game.players.lock().retain(|&id| id != second);
+    game.players.lock().dedup();
+    assert_eq!(game.deal(), Err(DealError::InconsistentState));
+
+    game.players.lock().push(second);
+    game.deal().unwrap();
+    assert_eq!(game.get_hands(first).unwrap().len(), 1);
+}
+
+#[test]
+fn insurance_outcome_reports_win_or_loss() {
+    fn insured_round(hole_rank: u8) -> bjrs::PlayerResult {
+        let game = Game::new(GameOptions::default(), 50);
+        let insured = game.join(chips(100));
+        let uninsured = game.join(chips(100));
+        game.start_betting();
+        game.bet(insured, chips(10)).unwrap();
+        game.bet(uninsured, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 10),
+                card(Suit::Clubs, 10),
+                card(Suit::Spades, 1),
+                card(Suit::Diamonds, 9),
+                card(Suit::Hearts, 9),
+                card(Suit::Clubs, hole_rank),
+            ],
+        );
+        game.deal().unwrap();
+        game.take_insurance(insured).unwrap();
+        game.decline_insurance(uninsured).unwrap();
+        if !game.finish_insurance().unwrap() {
+            game.stand(insured, 0).unwrap();
+            game.stand(uninsured, 0).unwrap();
+            game.dealer_play().unwrap();
+        }
+        let result = game.showdown().unwrap();
+        assert_eq!(result.players[1].insurance_outcome, None);
+        result.players[0].clone()
+    }
+
+    let won = insured_round(13);
+    assert_eq!(won.insurance_outcome, Some(HandOutcome::Win));
+    assert_eq!(won.insurance_payout, chips(15));
+
+    let lost = insured_round(8);
+    assert_eq!(lost.insurance_outcome, Some(HandOutcome::Lose));
+    assert_eq!(lost.insurance_payout, chips(0));
+}
+
+#[test]
+fn can_deal_reports_deal_errors_without_dealing() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 1);
+
+    assert_eq!(game.can_deal(), Err(DealError::InvalidState));
+
+    game.start_betting();
+    assert_eq!(game.can_deal(), Err(DealError::NoBets));
+
+    let player = game.join(chips(10));
+    game.bet(player, chips(5)).unwrap();
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 5),
+            card(Suit::Diamonds, 7),
+        ],
+    );
+
+    assert_eq!(game.can_deal(), Err(DealError::NotEnoughCards));
+    assert_eq!(game.can_deal(), game.deal());
+
+    game.decks.lock().insert(0, card(Suit::Spades, 4));
+    assert_eq!(game.can_deal(), Ok(()));
+    assert_eq!(*game.state.lock(), GameState::Betting);
+    assert_eq!(game.cards_remaining(), 4);
+    assert!(game.get_hands(player).is_none());
+    game.deal().unwrap();
+}
+
+#[test]
+fn hand_stands_at_max_cards_per_hand() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_max_cards_per_hand(5);
+    let game = Game::new(options, 51);
+    let player = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(&game, &[card(Suit::Spades, 1); 20]);
+    game.deal().unwrap();
+
+    for _ in 0..3 {
+        game.hit(player, 0).unwrap();
+    }
+    let hand = game.get_hands(player).unwrap()[0].clone();
+    assert_eq!(hand.len(), 5);
+    assert_eq!(hand.value(), 15);
+    assert_eq!(hand.status(), HandStatus::Stand);
+    assert_eq!(*game.state.lock(), GameState::DealerTurn);
+    assert_eq!(game.hit(player, 0), Err(ActionError::InvalidState));
+
+    assert_eq!(GameOptions::default().max_cards_per_hand, 11);
+}
+
+#[test]
+fn dealer_play_step_matches_dealer_play() {
+    fn dealer_turn_game() -> Game {
+        let options = GameOptions::default().with_insurance(false);
+        let game = Game::new(options, 52);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 10),
+                card(Suit::Clubs, 2),
+                card(Suit::Spades, 9),
+                card(Suit::Diamonds, 3),
+                card(Suit::Hearts, 4),
+                card(Suit::Clubs, 1),
+                card(Suit::Spades, 5),
+                card(Suit::Diamonds, 9),
+            ],
+        );
+        game.deal().unwrap();
+        game.stand(player, 0).unwrap();
+        game
+    }
+
+    let atomic = dealer_turn_game();
+    let expected = atomic.dealer_play().unwrap();
+
+    let stepwise = dealer_turn_game();
+    assert!(!stepwise.dealer_hand.lock().is_hole_revealed());
+    let mut drawn = Vec::new();
+    while let Some(card) = stepwise.dealer_play_step().unwrap() {
+        assert!(stepwise.dealer_hand.lock().is_hole_revealed());
+        assert_eq!(*stepwise.state.lock(), GameState::DealerTurn);
+        drawn.push(card);
+    }
+
+    assert_eq!(drawn, expected);
+    assert_eq!(drawn.len(), 2);
+    assert_eq!(*stepwise.state.lock(), GameState::RoundOver);
+    assert_eq!(
+        stepwise.dealer_hand.lock().value(),
+        atomic.dealer_hand.lock().value()
+    );
+    assert_eq!(
+        stepwise.dealer_play_step(),
+        Err(ShowdownError::InvalidState)
+    );
+}
+
+#[test]
+fn suit_colors() {
+    assert_eq!(Suit::Hearts.color(), Color::Red);
+    assert_eq!(Suit::Diamonds.color(), Color::Red);
+    assert_eq!(Suit::Clubs.color(), Color::Black);
+    assert_eq!(Suit::Spades.color(), Color::Black);
+
+    for suit in Suit::iter() {
+        assert_eq!(suit.is_red(), suit.color() == Color::Red);
+        assert_ne!(suit.is_red(), suit.is_black());
+    }
+    assert_eq!(Suit::iter().filter(|suit| suit.is_red()).count(), 2);
+}
+
+#[test]
+fn reshuffle_on_empty_recycles_discards() {
+    fn play_until_empty_hit(options: GameOptions) -> (Game, u8) {
+        let game = Game::new(options, 53);
+        let player = game.join(chips(100));
+
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 10),
+                card(Suit::Clubs, 10),
+                card(Suit::Spades, 9),
+                card(Suit::Diamonds, 8),
+            ],
+        );
+        game.deal().unwrap();
+        game.stand(player, 0).unwrap();
+        game.dealer_play().unwrap();
+        game.showdown().unwrap();
+        game.clear_round();
+
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 2),
+                card(Suit::Clubs, 9),
+                card(Suit::Spades, 3),
+                card(Suit::Diamonds, 7),
+            ],
+        );
+        game.deal().unwrap();
+        (game, player)
+    }
+
+    let (game, player) = play_until_empty_hit(GameOptions::default().with_insurance(false));
+    assert_eq!(game.cards_remaining(), 0);
+    assert_eq!(game.hit(player, 0), Err(ActionError::NoCards));
+
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_reshuffle_on_empty(true);
+    let (game, player) = play_until_empty_hit(options);
+    assert_eq!(game.discards().len(), 4);
+    let drawn = game.hit(player, 0).unwrap();
+    assert!(
+        [
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 8),
+        ]
+        .contains(&drawn)
+    );
+    assert!(game.discards().is_empty());
+    assert_eq!(game.cards_remaining(), 3);
+}
+
+#[test]
+fn standing_outcome_vs_upcard_compares_visible_total() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 54);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 4),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 13),
+            card(Suit::Hearts, 2),
+            card(Suit::Clubs, 10),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(
+        game.standing_outcome_vs_upcard(first, 0),
+        Some(StandingHint::Ahead)
+    );
+    assert_eq!(
+        game.standing_outcome_vs_upcard(second, 0),
+        Some(StandingHint::Tied)
+    );
+    assert_eq!(game.standing_outcome_vs_upcard(first, 1), None);
+}
+
+#[test]
+fn player_betting_whole_bankroll_can_finish_the_round() {
+    let game = Game::new(GameOptions::default(), 55);
+    let player = game.join(chips(20));
+
+    game.start_betting();
+    game.bet(player, chips(20)).unwrap();
+    assert_eq!(game.get_money(player), Some(chips(0)));
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 1),
+            card(Suit::Spades, 8),
+            card(Suit::Diamonds, 7),
+            card(Suit::Hearts, 4),
+            card(Suit::Clubs, 10),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(
+        game.take_insurance(player),
+        Err(InsuranceError::InsufficientFunds)
+    );
+    game.decline_insurance(player).unwrap();
+    assert!(!game.finish_insurance().unwrap());
+
+    assert_eq!(
+        game.double_down(player, 0),
+        Err(ActionError::InsufficientFunds)
+    );
+    assert_eq!(game.split(player, 0), Err(ActionError::InsufficientFunds));
+    game.hit(player, 0).unwrap();
+    game.stand(player, 0).unwrap();
+    assert_eq!(game.get_money(player), Some(chips(0)));
+
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(game.get_money(player), Some(chips(40)));
+}
+
+#[test]
+fn new_ordered_draws_in_canonical_order() {
+    let game = Game::new_ordered(GameOptions::default().with_decks(2));
+    assert_eq!(game.cards_remaining(), 2 * DECK_SIZE);
+
+    let drawn: Vec<Card> = core::iter::from_fn(|| game.draw_card()).collect();
+    let deck = standard_deck();
+    assert_eq!(drawn.len(), 2 * DECK_SIZE);
+    assert_eq!(&drawn[..DECK_SIZE], &deck);
+    assert_eq!(&drawn[DECK_SIZE..], &deck);
+
+    let game = Game::new_ordered(GameOptions::default().with_insurance(false));
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    let hand = game.get_hands(player).unwrap()[0].clone();
+    assert_eq!(
+        hand.cards(),
+        &[card(Suit::Hearts, 1), card(Suit::Hearts, 3)]
+    );
+    assert_eq!(
+        game.dealer_hand.lock().up_card(),
+        Some(&card(Suit::Hearts, 2))
+    );
+}
+
+#[test]
+fn round_result_totals_insurance_across_players() {
+    let game = Game::new(GameOptions::default(), 56);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    let third = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(30)).unwrap();
+    game.bet(third, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 8),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 5),
+            card(Suit::Clubs, 13),
+        ],
+    );
+    game.deal().unwrap();
+    game.take_insurance(first).unwrap();
+    game.take_insurance(second).unwrap();
+    game.decline_insurance(third).unwrap();
+    assert!(game.finish_insurance().unwrap());
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.total_insurance_bet, chips(5 + 15));
+    assert_eq!(result.total_insurance_payout, chips(15 + 45));
+    assert_eq!(
+        result.total_insurance_payout,
+        result
+            .players
+            .iter()
+            .map(|player| player.insurance_payout)
+            .sum::<Chips>()
+    );
+}
+
+#[test]
+fn preview_if_all_stand_matches_standing_everyone() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 57);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    assert_eq!(
+        game.preview_if_all_stand().unwrap_err(),
+        ShowdownError::InvalidState
+    );
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 8),
+            card(Suit::Hearts, 7),
+            card(Suit::Clubs, 4),
+            card(Suit::Spades, 5),
+            card(Suit::Diamonds, 3),
+        ],
+    );
+    game.deal().unwrap();
+
+    let preview = game.preview_if_all_stand().unwrap();
+    assert_eq!(*game.state.lock(), GameState::PlayerTurn);
+    assert_eq!(game.cards_remaining(), 2);
+    assert_eq!(game.dealer_hand.lock().len(), 2);
+    assert_eq!(game.get_money(first), Some(chips(90)));
+
+    game.stand(first, 0).unwrap();
+    game.stand(second, 0).unwrap();
+    game.dealer_play().unwrap();
+    let actual = game.showdown().unwrap();
+
+    assert_eq!(preview.dealer_value, 18);
+    assert_eq!(preview.dealer_value, actual.dealer_value);
+    assert_eq!(preview.ledger, actual.ledger);
+    let nets = |result: &bjrs::RoundResult| -> Vec<(u8, i64)> {
+        result
+            .players
+            .iter()
+            .map(|player| (player.player_id, player.net))
+            .collect()
+    };
+    assert_eq!(nets(&preview), vec![(first, 0), (second, -20)]);
+    assert_eq!(nets(&preview), nets(&actual));
+}
+
+#[test]
+fn remove_card_takes_one_copy_out_of_the_shoe() {
+    let game = Game::new(GameOptions::default().with_decks(1), 58);
+    let ace = card(Suit::Spades, 1);
+
+    assert_eq!(game.remaining_composition()[0], 4);
+    game.remove_card(ace).unwrap();
+    assert_eq!(game.cards_remaining(), DECK_SIZE - 1);
+    assert_eq!(game.remaining_composition()[0], 3);
+    assert_eq!(game.discards(), vec![ace]);
+    assert!(!game.decks.lock().contains(&ace));
+
+    assert_eq!(game.remove_card(ace), Err(ShoeError::CardNotFound));
+    assert_eq!(game.cards_remaining(), DECK_SIZE - 1);
+
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(
+        game.remove_card(card(Suit::Hearts, 1)),
+        Err(ShoeError::InvalidState)
+    );
+}
+
+#[test]
+fn betting_order_follows_seat_order() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 59);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    let third = game.join(chips(100));
+    let idle = game.join(chips(100));
+
+    game.start_betting();
+    assert!(game.betting_order().is_empty());
+    game.bet(third, chips(10)).unwrap();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    game.deal().unwrap();
+
+    let order = game.betting_order();
+    assert_eq!(order, vec![first, second, third]);
+    assert!(!order.contains(&idle));
+
+    game.clear_round();
+    assert!(game.betting_order().is_empty());
+}
+
+#[test]
+fn can_hit_only_for_active_hands_below_21() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 60);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 7),
+            card(Suit::Diamonds, 5),
+            card(Suit::Hearts, 2),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 10),
+        ],
+    );
+    game.deal().unwrap();
+
+    // First player holds 15 and is to act; the second is still waiting.
+    assert!(game.can_hit(first, 0));
+    assert!(!game.can_hit(second, 0));
+    assert!(!game.can_hit(first, 1));
+
+    game.stand(first, 0).unwrap();
+    assert!(!game.can_hit(first, 0));
+
+    // Second player draws to 21: still active, but hitting makes no sense.
+    assert!(game.can_hit(second, 0));
+    game.hit(second, 0).unwrap();
+    assert_eq!(game.get_hands(second).unwrap()[0].value(), 21);
+    assert_eq!(
+        game.get_hands(second).unwrap()[0].status(),
+        HandStatus::Active
+    );
+    assert!(!game.can_hit(second, 0));
+}
+
+#[test]
+fn clear_round_keep_bets_restakes_bets() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 61);
+    let first = game.join(chips(100));
+    let second = game.join(chips(25));
+
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 10),
+            card(Suit::Diamonds, 9),
+            card(Suit::Hearts, 7),
+            card(Suit::Clubs, 8),
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(first, 0).unwrap();
+    game.stand(second, 0).unwrap();
+    game.dealer_play().unwrap();
+    game.showdown().unwrap();
+    assert_eq!(game.get_money(first), Some(chips(110)));
+    assert_eq!(game.get_money(second), Some(chips(5)));
+
+    game.clear_round_keep_bets().unwrap();
+    assert!(game.get_hands(first).is_none());
+    assert!(game.dealer_hand.lock().is_empty());
+    assert!(game.betting_order().is_empty());
+    assert_eq!(*game.state.lock(), GameState::WaitingForPlayers);
+
+    // The winner's bet is staked again; the loser can no longer cover theirs.
+    assert_eq!(game.bets.lock().get(&first), Some(&chips(10)));
+    assert_eq!(game.bets.lock().get(&second), None);
+    assert_eq!(game.get_money(first), Some(chips(100)));
+    assert_eq!(game.get_money(second), Some(chips(5)));
+
+    game.start_betting();
+    assert_eq!(game.cancel_bet(first), Ok(chips(10)));
+    assert_eq!(game.get_money(first), Some(chips(110)));
+}
+
+#[test]
+fn clear_round_keep_bets_only_restakes_once_after_the_round() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 61);
+    let player = game.join(chips(100));
+    let limited = game.join(chips(100));
+
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.bet(limited, chips(10)).unwrap();
+    assert_eq!(game.clear_round_keep_bets(), Err(BetError::InvalidState));
+    assert_eq!(game.get_money(player), Some(chips(90)));
+    assert_eq!(game.get_bet(player), Some(chips(10)));
+
+    // Bets already placed are kept when the limits change
+    game.update_options(|options| options.min_bet = chips(10))
+        .unwrap();
+    game.cancel_bet(limited).unwrap();
+    game.bet(limited, chips(15)).unwrap();
+    game.update_options(|options| options.bet_increment = chips(10))
+        .unwrap();
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 10),
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 8),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(player, 0).unwrap();
+    game.stand(limited, 0).unwrap();
+    game.dealer_play().unwrap();
+    game.showdown().unwrap();
+    assert_eq!(game.get_money(player), Some(chips(100)));
+    assert_eq!(game.get_money(limited), Some(chips(100)));
+
+    game.clear_round_keep_bets().unwrap();
+    assert_eq!(game.get_money(player), Some(chips(90)));
+    // 15 is no longer a multiple of the increment, so it is dropped
+    assert_eq!(game.get_bet(limited), None);
+    assert_eq!(game.get_money(limited), Some(chips(100)));
+
+    assert_eq!(game.clear_round_keep_bets(), Err(BetError::InvalidState));
+    assert_eq!(game.get_money(player), Some(chips(90)));
+    assert_eq!(game.get_bet(player), Some(chips(10)));
+}
+
+#[test]
+fn transition_rejects_illegal_edges() {
+    let game = Game::new(GameOptions::default(), 62);
+
+    assert_eq!(
+        game.transition(GameState::DealerTurn),
+        Err(StateError::IllegalTransition {
+            from: GameState::WaitingForPlayers,
+            to: GameState::DealerTurn,
+        })
+    );
+    assert_eq!(game.state(), GameState::WaitingForPlayers);
+
+    game.transition(GameState::Betting).unwrap();
+    game.transition(GameState::Dealing).unwrap();
+    game.transition(GameState::Insurance).unwrap();
+    game.transition(GameState::PlayerTurn).unwrap();
+    game.transition(GameState::DealerTurn).unwrap();
+    game.transition(GameState::RoundOver).unwrap();
+    assert!(game.transition(GameState::PlayerTurn).is_err());
+    assert!(game.transition(GameState::Betting).is_err());
+    game.transition(GameState::WaitingForPlayers).unwrap();
+
+    assert!(!GameState::Insurance.can_transition_to(GameState::DealerTurn));
+    assert!(GameState::RoundOver.can_transition_to(GameState::RoundOver));
+}
+
+#[test]
+fn naturals_this_round_lists_players_dealt_blackjack() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 63);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+
+    game.start_betting();
+    assert!(game.naturals_this_round().is_empty());
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 1),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 10),
+            card(Suit::Hearts, 12),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 2),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(game.naturals_this_round(), vec![second]);
+
+    game.stand(first, 0).unwrap();
+    game.dealer_play().unwrap();
+    game.showdown().unwrap();
+    assert_eq!(game.naturals_this_round(), vec![second]);
+
+    game.clear_round();
+    assert!(game.naturals_this_round().is_empty());
+}
+
+#[test]
+fn adjust_funds_credits_and_rejects_underflow() {
+    let game = Game::new(GameOptions::default(), 64);
+    let player = game.join(chips(50));
+
+    assert_eq!(game.adjust_funds(player, 25), Ok(chips(75)));
+    assert_eq!(game.adjust_funds(player, -30), Ok(chips(45)));
+    assert_eq!(
+        game.adjust_funds(player, -46),
+        Err(FundsError::InsufficientFunds)
+    );
+    assert_eq!(game.get_money(player), Some(chips(45)));
+    assert_eq!(game.adjust_funds(player, -45), Ok(chips(0)));
+    assert_eq!(game.adjust_funds(99, 10), Err(FundsError::PlayerNotFound));
+
+    game.adjust_funds(player, 20).unwrap();
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.deal().unwrap();
+    assert_eq!(game.adjust_funds(player, 5), Err(FundsError::InvalidState));
+    assert_eq!(game.get_money(player), Some(chips(10)));
+}
+
+#[test]
+fn state_fingerprint_tracks_visible_state() {
+    fn dealt_game(hole_rank: u8) -> (Game, u8) {
+        let options = GameOptions::default().with_insurance(false);
+        let game = Game::new(options, 65);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 5),
+                card(Suit::Clubs, 9),
+                card(Suit::Spades, 6),
+                card(Suit::Diamonds, hole_rank),
+                card(Suit::Hearts, 3),
+                card(Suit::Clubs, 4),
+            ],
+        );
+        game.deal().unwrap();
+        (game, player)
+    }
+
+    let (left, left_player) = dealt_game(7);
+    let (right, right_player) = dealt_game(7);
+    assert_eq!(left.state_fingerprint(), right.state_fingerprint());
+
+    // The hole card is hidden, so it does not affect the fingerprint.
+    let (hidden, _) = dealt_game(8);
+    assert_eq!(left.state_fingerprint(), hidden.state_fingerprint());
+
+    left.hit(left_player, 0).unwrap();
+    assert_ne!(left.state_fingerprint(), right.state_fingerprint());
+    right.hit(right_player, 0).unwrap();
+    assert_eq!(left.state_fingerprint(), right.state_fingerprint());
+
+    left.stand(left_player, 0).unwrap();
+    right.hit(right_player, 0).unwrap();
+    assert_ne!(left.state_fingerprint(), right.state_fingerprint());
+}
+
+#[test]
+fn initial_cards_sets_cards_dealt_per_player() {
+    fn dealt_game(options: GameOptions) -> (Game, u8, u8) {
+        let game = Game::new(options.with_insurance(false), 66);
+        let first = game.join(chips(100));
+        let second = game.join(chips(100));
+        game.start_betting();
+        game.bet(first, chips(10)).unwrap();
+        game.bet(second, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 2),
+                card(Suit::Clubs, 3),
+                card(Suit::Spades, 9),
+                card(Suit::Diamonds, 4),
+                card(Suit::Hearts, 5),
+                card(Suit::Clubs, 7),
+            ],
+        );
+        game.deal().unwrap();
+        (game, first, second)
+    }
+
+    let (game, first, second) = dealt_game(GameOptions::default());
+    assert_eq!(game.get_hands(first).unwrap()[0].len(), 2);
+    assert_eq!(game.get_hands(second).unwrap()[0].len(), 2);
+    assert_eq!(game.get_dealer_hand().len(), 2);
+    assert_eq!(game.get_dealer_hand().cards()[1], card(Suit::Clubs, 7));
+
+    let (game, first, second) = dealt_game(GameOptions::default().with_initial_cards(1));
+    let first_hand = &game.get_hands(first).unwrap()[0];
+    assert_eq!(first_hand.cards(), [card(Suit::Hearts, 2)]);
+    let second_hand = &game.get_hands(second).unwrap()[0];
+    assert_eq!(second_hand.cards(), [card(Suit::Clubs, 3)]);
+    assert_eq!(game.get_dealer_hand().cards(), [card(Suit::Spades, 9)]);
+}
+
+#[test]
+fn showdown_into_reuses_buffer_across_rounds() {
+    fn play_to_round_over(game: &Game, players: &[u8]) {
+        game.start_betting();
+        for &player in players {
+            game.bet(player, chips(10)).unwrap();
+        }
+        game.deal().unwrap();
+        while let Some(player) = game.current_player() {
+            game.stand(player, game.current_turn().hand_index).unwrap();
+        }
+        if game.state() == GameState::DealerTurn {
+            game.dealer_play().unwrap();
+        }
+    }
+
+    let options = GameOptions::default().with_insurance(false);
+    let reused = Game::new(options.clone(), 67);
+    let fresh = Game::new(options, 67);
+    let players = [reused.join(chips(1_000)), reused.join(chips(1_000))];
+    fresh.join(chips(1_000));
+    fresh.join(chips(1_000));
+
+    let mut buf = Vec::new();
+    for _ in 0..5 {
+        play_to_round_over(&reused, &players);
+        play_to_round_over(&fresh, &players);
+
+        reused.showdown_into(&mut buf).unwrap();
+        let expected = fresh.showdown().unwrap();
+        assert_eq!(buf.len(), expected.players.len());
+        for (actual, expected) in buf.iter().zip(&expected.players) {
+            assert_eq!(actual.player_id, expected.player_id);
+            assert_eq!(actual.hands, expected.hands);
+            assert_eq!(actual.total_payout, expected.total_payout);
+            assert_eq!(actual.net, expected.net);
+        }
+        for &player in &players {
+            assert_eq!(reused.get_money(player), fresh.get_money(player));
+        }
+
+        reused.clear_round();
+        fresh.clear_round();
+    }
+
+    reused.start_betting();
+    assert_eq!(
+        reused.showdown_into(&mut buf).unwrap_err(),
+        ShowdownError::InvalidState
+    );
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn players_with_live_hands_skips_busted_players() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 68);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 6),
+            card(Suit::Diamonds, 6),
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 13),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.players_with_live_hands(), [first, second]);
+
+    game.hit(first, 0).unwrap();
+    assert_eq!(game.players_with_live_hands(), [second]);
+    game.stand(second, 0).unwrap();
+    assert_eq!(game.players_with_live_hands(), [second]);
+}
+
+#[test]
+fn options_validate_rejects_unplayable_settings() {
+    assert_eq!(GameOptions::default().validate(), Ok(()));
+    assert_eq!(
+        GameOptions::default().with_decks(0).validate(),
+        Err(OptionsError::NoDecks)
+    );
+    assert_eq!(
+        GameOptions::default().with_penetration(1.5).validate(),
+        Err(OptionsError::InvalidPenetration)
+    );
+    assert_eq!(
+        GameOptions::default()
+            .with_blackjack_pays(Payout::new(3, 0))
+            .validate(),
+        Err(OptionsError::InvalidPayout)
+    );
+
+    let game = Game::new(GameOptions::default(), 69);
+    assert_eq!(
+        game.update_options(|options| options.decks = 0)
+            .unwrap_err(),
+        OptionsError::NoDecks
+    );
+    assert_eq!(game.options().decks, 2);
+}
+
+#[test]
+fn try_new_validates_options() {
+    assert!(Game::try_new(GameOptions::default(), 70).is_ok());
+    assert_eq!(
+        Game::try_new(GameOptions::default().with_decks(0), 70).err(),
+        Some(OptionsError::NoDecks)
+    );
+}
+
+#[test]
+fn hand_split_separates_a_pair() {
+    let mut pair = Hand::new(chips(25));
+    pair.add_card(card(Suit::Spades, 9));
+    pair.add_card(card(Suit::Hearts, 9));
+    let split = pair.split().unwrap();
+    assert_eq!(pair.cards(), [card(Suit::Spades, 9)]);
+    assert_eq!(split.cards(), [card(Suit::Hearts, 9)]);
+    assert_eq!(split.bet(), chips(25));
+    assert!(split.is_from_split());
+    assert_eq!(split.status(), HandStatus::Active);
+
+    let mut mixed = Hand::new(chips(25));
+    mixed.add_card(card(Suit::Spades, 9));
+    mixed.add_card(card(Suit::Hearts, 8));
+    assert!(!mixed.can_split());
+    assert!(mixed.split().is_none());
+    assert_eq!(mixed.len(), 2);
+}
+
+#[test]
+fn can_split_and_split_agree_on_unlike_tens() {
+    let mut tens = Hand::new(chips(25));
+    tens.add_card(card(Suit::Spades, 11));
+    tens.add_card(card(Suit::Hearts, 13));
+    assert!(tens.can_split());
+    assert!(tens.split().is_some());
+
+    // The table decides whether unlike tens may be split
+    for split_any_ten in [false, true] {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_split_any_ten(split_any_ten);
+        let game = Game::new(options, 72);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Spades, 11),
+                card(Suit::Clubs, 10),
+                card(Suit::Hearts, 13),
+                card(Suit::Diamonds, 7),
+            ],
+        );
+        game.deal().unwrap();
+        assert!(game.get_hands(player).unwrap()[0].can_split());
+        assert_eq!(
+            game.split_deny_reason(player, 0),
+            (!split_any_ten).then_some(SplitDenyReason::UnlikeTensDisallowed)
+        );
+    }
+}
+
+#[test]
+fn dealer_peek_ends_round_on_dealer_natural() {
+    fn dealt_game(options: GameOptions) -> (Game, u8, u8) {
+        let game = Game::new(options, 71);
+        let natural = game.join(chips(100));
+        let eighteen = game.join(chips(100));
+        game.start_betting();
+        game.bet(natural, chips(10)).unwrap();
+        game.bet(eighteen, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 1),
+                card(Suit::Clubs, 10),
+                card(Suit::Spades, 13),
+                card(Suit::Diamonds, 13),
+                card(Suit::Hearts, 8),
+                card(Suit::Clubs, 1),
+            ],
+        );
+        game.deal().unwrap();
+        (game, natural, eighteen)
+    }
+
+    let (game, _, _) = dealt_game(GameOptions::default());
+    assert_eq!(game.state(), GameState::PlayerTurn);
+    assert!(!game.dealer_has_blackjack());
+
+    let (game, natural, eighteen) = dealt_game(GameOptions::default().with_dealer_peek(true));
+    assert_eq!(game.state(), GameState::RoundOver);
+    assert!(game.dealer_has_blackjack());
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].player_id, natural);
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Push);
+    assert_eq!(result.players[1].player_id, eighteen);
+    assert_eq!(result.players[1].hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(game.get_money(natural), Some(chips(100)));
+    assert_eq!(game.get_money(eighteen), Some(chips(90)));
+}
+
+#[test]
+fn blackjack_qualifier_controls_blackjack_payout() {
+    fn three_card_21(options: GameOptions) -> (Game, u8) {
+        let game = Game::new(options.with_insurance(false), 72);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 7),
+                card(Suit::Clubs, 10),
+                card(Suit::Spades, 7),
+                card(Suit::Diamonds, 9),
+                card(Suit::Clubs, 7),
+            ],
+        );
+        game.deal().unwrap();
+        game.hit(player, 0).unwrap();
+        (game, player)
+    }
+
+    // By default a three-card 21 is an ordinary 21 paid even money.
+    let (game, player) = three_card_21(GameOptions::default());
+    assert_eq!(
+        game.get_hands(player).unwrap()[0].status(),
+        HandStatus::Active
+    );
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(game.get_money(player), Some(chips(110)));
+
+    let options = GameOptions::default().with_blackjack_on(BlackjackQualifier::AnyTwentyOne);
+    let (game, player) = three_card_21(options);
+    assert_eq!(
+        game.get_hands(player).unwrap()[0].status(),
+        HandStatus::Blackjack
+    );
+    assert_eq!(game.state(), GameState::DealerTurn);
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Blackjack);
+    assert_eq!(game.get_money(player), Some(chips(115)));
+
+    let strict = Hand::new(chips(10)).with_blackjack_qualifier(BlackjackQualifier::SuitedAceKing);
+    let mut unsuited = strict.clone();
+    unsuited.add_card(card(Suit::Spades, 1));
+    unsuited.add_card(card(Suit::Hearts, 13));
+    assert!(!unsuited.is_blackjack());
+    assert_eq!(unsuited.status(), HandStatus::Stand);
+    let mut suited = strict;
+    suited.add_card(card(Suit::Spades, 1));
+    suited.add_card(card(Suit::Spades, 13));
+    assert!(suited.is_blackjack());
+}
+
+#[test]
+fn unqualified_naturals_stand_and_tie_a_dealer_natural() {
+    fn play(dealer_hole: Card) -> (Game, u8) {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_dealer_peek(true)
+            .with_blackjack_on(BlackjackQualifier::SuitedAceKing);
+        let game = Game::new(options, 76);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Spades, 1),
+                card(Suit::Clubs, 10),
+                card(Suit::Hearts, 13),
+                dealer_hole,
+            ],
+        );
+        game.deal().unwrap();
+        let hand = &game.get_hands(player).unwrap()[0];
+        assert!(hand.is_natural());
+        assert!(!hand.is_blackjack());
+        assert_eq!(hand.status(), HandStatus::Stand);
+        (game, player)
+    }
+
+    // An ordinary 21 is stood at once and wins even money
+    let (game, player) = play(card(Suit::Diamonds, 8));
+    assert_eq!(game.current_player(), None);
+    assert_eq!(game.state(), GameState::DealerTurn);
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(game.get_money(player), Some(chips(110)));
+
+    // Against a dealer natural it ties instead of losing
+    let (game, player) = play(card(Suit::Diamonds, 1));
+    assert_eq!(game.state(), GameState::RoundOver);
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Push);
+    assert_eq!(game.get_money(player), Some(chips(100)));
+}
+
+#[test]
+fn play_round_skips_straight_to_the_dealer_after_a_natural() {
+    let options = GameOptions::default()
+        .with_penetration(0.0)
+        .with_insurance(false)
+        .with_blackjack_on(BlackjackQualifier::SuitedAceKing);
+    let game = Game::new(options, 77);
+    let player = game.join(chips(100));
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Spades, 1),
+            card(Suit::Clubs, 10),
+            card(Suit::Hearts, 13),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+
+    let result = game
+        .play_round(&[(player, chips(10))], |_, _, _| {
+            unreachable!("no hand is left to play")
+        })
+        .unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(game.get_money(player), Some(chips(110)));
+    assert_eq!(game.state(), GameState::WaitingForPlayers);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn core_types_round_trip_through_serde() {
+    let options = GameOptions::default()
+        .with_insurance(false)
+        .with_blackjack_on(BlackjackQualifier::SuitedAceKing);
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(serde_json::from_str::<GameOptions>(&json).unwrap(), options);
+
+    let game = Game::new(options, 73);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 8),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    game.deal().unwrap();
+
+    let hand = &game.get_hands(player).unwrap()[0];
+    let json = serde_json::to_string(hand).unwrap();
+    let restored: Hand = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.cards(), hand.cards());
+    assert_eq!(restored.bet(), hand.bet());
+
+    let json = serde_json::to_string(&game.state()).unwrap();
+    assert_eq!(
+        serde_json::from_str::<GameState>(&json).unwrap(),
+        GameState::PlayerTurn
+    );
+
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    let json = serde_json::to_string(&result).unwrap();
+    let restored: bjrs::RoundResult = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.ledger, result.ledger);
+    assert_eq!(restored.players[0].hands, result.players[0].hands);
+}
+
+#[test]
+fn snapshot_restore_resumes_mid_round() {
+    let options = GameOptions::default().with_insurance(false);
+    let game = Game::new(options, 74);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    game.start_betting();
+    game.bet(first, chips(10)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 5),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 6),
+            card(Suit::Hearts, 7),
+            card(Suit::Clubs, 7),
+            card(Suit::Spades, 4),
+            card(Suit::Hearts, 2),
+        ],
+    );
+    game.deal().unwrap();
+    game.stand(first, 0).unwrap();
+
+    let snapshot = game.snapshot();
+    #[cfg(feature = "serde")]
+    let snapshot = {
+        let json = serde_json::to_string(&snapshot).unwrap();
+        serde_json::from_str(&json).unwrap()
+    };
+    let restored = Game::restore(snapshot);
+    assert_eq!(restored.state(), GameState::PlayerTurn);
+    assert_eq!(restored.current_player(), Some(second));
+    assert_eq!(restored.state_fingerprint(), game.state_fingerprint());
+    assert_eq!(restored.cards_remaining(), game.cards_remaining());
+
+    for table in [&game, &restored] {
+        table.hit(second, 0).unwrap();
+        table.stand(second, 0).unwrap();
+        table.dealer_play().unwrap();
+        table.showdown().unwrap();
+        table.clear_round();
+    }
+    assert_eq!(restored.get_money(first), game.get_money(first));
+    assert_eq!(restored.get_money(second), game.get_money(second));
+    assert_eq!(restored.state_fingerprint(), game.state_fingerprint());
+
+    // The generator state carries over, so later shuffles match too.
+    game.reshuffle().unwrap();
+    restored.reshuffle().unwrap();
+    assert_eq!(restored.draw_card(), game.draw_card());
+}
+
+#[test]
+fn replaying_action_log_rebuilds_game() {
+    let options = GameOptions::default().with_decks(1).with_penetration(0.5);
+    let game = Game::new(options.clone(), 75);
+    let first = game.join(chips(500));
+    let second = game.join(chips(500));
+
+    game.simulate(30, chips(10), |_, hand, _| {
+        if hand.can_split() && hand.cards()[0].rank == 8 {
+            PlayerDecision::Split
+        } else if hand.len() == 2 && hand.value() == 11 {
+            PlayerDecision::Double
+        } else if hand.value() < 13 {
+            PlayerDecision::Hit
+        } else {
+            PlayerDecision::Stand
+        }
+    })
+    .unwrap();
+    game.adjust_funds(first, 25).unwrap();
+    game.start_betting();
+    game.bet(first, chips(20)).unwrap();
+    game.bet(second, chips(20)).unwrap();
+    game.deal().unwrap();
+
+    let log = game.action_log();
+    assert!(log.contains(&LoggedAction::AdjustFunds {
+        player_id: first,
+        delta: 25
+    }));
+    // Failed calls leave no trace.
+    assert!(game.bet(first, chips(10)).is_err());
+    assert_eq!(game.action_log(), log);
+
+    let replayed = Game::replay(options.clone(), 75, &log).unwrap();
+    assert_eq!(replayed.state_fingerprint(), game.state_fingerprint());
+    assert_eq!(replayed.get_money(first), game.get_money(first));
+    assert_eq!(replayed.get_money(second), game.get_money(second));
+    assert_eq!(replayed.cards_remaining(), game.cards_remaining());
+    assert_eq!(replayed.action_log(), log);
+
+    // The same log against a different shoe diverges.
+    let tampered = [LoggedAction::Deal];
+    assert_eq!(
+        Game::replay(options, 75, &tampered).err(),
+        Some(ReplayError::ActionFailed { index: 0 })
+    );
+}
+
+#[test]
+fn apply_routes_actions_to_game_methods() {
+    let game = Game::new(GameOptions::default(), 76);
+    let first = game.join(chips(100));
+    let second = game.join(chips(100));
+    game.start_betting();
+    game.apply(first, Action::Bet(chips(20))).unwrap();
+    game.apply(second, Action::Bet(chips(10))).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 8),
+            card(Suit::Hearts, 7),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 3),
+            card(Suit::Diamonds, 2),
+            card(Suit::Hearts, 9),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.state(), GameState::Insurance);
+    game.apply(first, Action::DeclineInsurance).unwrap();
+    game.apply(second, Action::TakeInsurance).unwrap();
+    assert_eq!(game.get_insurance_bet(second), Some(chips(5)));
+    game.finish_insurance().unwrap();
+
+    assert_eq!(
+        game.apply(second, Action::Hit).unwrap_err(),
+        RoundError::Action(ActionError::NotYourTurn)
+    );
+    game.apply(first, Action::Split).unwrap();
+    assert_eq!(game.get_hands(first).unwrap().len(), 2);
+    game.apply(first, Action::Stand).unwrap();
+    game.apply(first, Action::Double).unwrap();
+    assert_eq!(game.get_hands(first).unwrap()[1].bet(), chips(40));
+    game.apply(second, Action::Surrender).unwrap();
+    assert_eq!(game.state(), GameState::DealerTurn);
+    assert_eq!(
+        game.apply(first, Action::Bet(chips(10))).unwrap_err(),
+        RoundError::Bet(BetError::InvalidState)
+    );
+}
+
+#[test]
+fn available_actions_reflects_rules_funds_and_turn() {
+    let game = Game::new(
+        GameOptions::default().with_surrender(SurrenderRule::Late),
+        1,
+    );
+    let rich = game.join(chips(500));
+    let poor = game.join(chips(100));
+    game.start_betting();
+    game.bet(rich, chips(10)).unwrap();
+    game.bet(poor, chips(60)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 8),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 8),
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 3),
+            card(Suit::Diamonds, 2),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert!(game.available_actions(rich, 0).insurance);
+    game.decline_insurance(rich).unwrap();
+    assert_eq!(game.available_actions(rich, 0), ActionSet::default());
+    assert!(game.available_actions(poor, 0).insurance);
+    game.decline_insurance(poor).unwrap();
+    game.finish_insurance().unwrap();
+
+    assert_eq!(
+        game.available_actions(rich, 0),
+        ActionSet {
+            hit: true,
+            stand: true,
+            double: true,
+            split: true,
+            surrender: true,
+            insurance: false,
+            switch: false,
+        }
+    );
+    assert_eq!(game.available_actions(poor, 0), ActionSet::default());
+
+    game.split(rich, 0).unwrap();
+    let actions = game.available_actions(rich, 0);
+    assert!(actions.double);
+    assert!(!actions.split);
+
+    game.stand(rich, 0).unwrap();
+    game.stand(rich, 1).unwrap();
+    let actions = game.available_actions(poor, 0);
+    assert!(actions.hit && actions.stand && actions.surrender);
+    assert!(!actions.double);
+    assert!(!actions.split);
+}
+
+#[test]
+fn drained_events_describe_a_round() {
+    let game = Game::new(GameOptions::default(), 1);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 7),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    game.deal().unwrap();
+    assert!(game.drain_events().is_empty());
+
+    game.clear_round();
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 7),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    game.set_event_recording(true);
+    game.deal().unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    game.showdown().unwrap();
+
+    let to_player = CardRecipient::Player {
+        player_id: player,
+        hand_index: 0,
+    };
+    assert_eq!(
+        game.drain_events(),
+        [
+            GameEvent::CardDealt {
+                recipient: to_player,
+                card: card(Suit::Hearts, 10),
+                face_up: true,
+            },
+            GameEvent::CardDealt {
+                recipient: CardRecipient::Dealer,
+                card: card(Suit::Clubs, 9),
+                face_up: true,
+            },
+            GameEvent::CardDealt {
+                recipient: to_player,
+                card: card(Suit::Spades, 7),
+                face_up: true,
+            },
+            GameEvent::CardDealt {
+                recipient: CardRecipient::Dealer,
+                card: card(Suit::Diamonds, 8),
+                face_up: false,
+            },
+            GameEvent::StateChanged {
+                from: GameState::Betting,
+                to: GameState::PlayerTurn,
+            },
+            GameEvent::StateChanged {
+                from: GameState::PlayerTurn,
+                to: GameState::DealerTurn,
+            },
+            GameEvent::DealerRevealedHole {
+                card: card(Suit::Diamonds, 8),
+            },
+            GameEvent::StateChanged {
+                from: GameState::DealerTurn,
+                to: GameState::RoundOver,
+            },
+            GameEvent::PayoutSettled {
+                player_id: player,
+                payout: chips(10),
+                net: 0,
+            },
+        ]
+    );
+    assert!(game.drain_events().is_empty());
+}
+
+#[test]
+fn bets_must_respect_table_limits() {
+    let options = GameOptions::default()
+        .with_bet_limits(chips(10), chips(100))
+        .with_bet_increment(chips(5));
+    let game = Game::new(options, 1);
+    let player = game.join(chips(500));
+    game.start_betting();
+
+    assert_eq!(game.bet(player, chips(5)), Err(BetError::BelowMinimum));
+    assert_eq!(game.bet(player, chips(105)), Err(BetError::AboveMaximum));
+    assert_eq!(game.bet(player, chips(12)), Err(BetError::InvalidIncrement));
+    assert_eq!(game.get_money(player), Some(chips(500)));
+
+    game.bet(player, chips(100)).unwrap();
+    assert_eq!(game.get_bet(player), Some(chips(100)));
+
+    assert_eq!(
+        GameOptions::default()
+            .with_bet_increment(chips(0))
+            .validate(),
+        Err(OptionsError::InvalidBetLimits)
+    );
+    assert_eq!(
+        GameOptions::default()
+            .with_bet_limits(chips(50), chips(10))
+            .validate(),
+        Err(OptionsError::InvalidBetLimits)
+    );
+}
+
+#[test]
+fn dealer_peek_on_ten_without_blackjack_keeps_hole_card_hidden() {
+    let game = Game::new(GameOptions::default().with_dealer_peek(true), 73);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 12),
+            card(Suit::Spades, 9),
+            card(Suit::Diamonds, 7),
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(game.state(), GameState::PlayerTurn);
+    assert!(!game.get_dealer_hand().is_hole_revealed());
+    assert!(!game.dealer_has_blackjack());
+    assert_eq!(game.current_player(), Some(player));
+}
+
+#[test]
+fn revise_insurance_rejects_even_money_and_early_surrender() {
+    let options = GameOptions::default().with_surrender(SurrenderRule::Early);
+    let game = Game::new(options, 74);
+    let natural = game.join(chips(100));
+    let quitter = game.join(chips(100));
+    game.start_betting();
+    game.bet(natural, chips(10)).unwrap();
+    game.bet(quitter, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),   // natural
+            card(Suit::Clubs, 9),    // quitter
+            card(Suit::Spades, 1),   // dealer up (Ace)
+            card(Suit::Hearts, 13),  // natural
+            card(Suit::Clubs, 7),    // quitter
+            card(Suit::Diamonds, 6), // dealer hole
+        ],
+    );
+    game.deal().unwrap();
+
+    assert_eq!(game.take_even_money(natural, 0), Ok(chips(20)));
+    assert_eq!(game.surrender(quitter, 0), Ok(chips(5)));
+    for take in [true, false] {
+        assert_eq!(
+            game.revise_insurance(natural, take),
+            Err(InsuranceError::AlreadyDecided)
+        );
+        assert_eq!(
+            game.revise_insurance(quitter, take),
+            Err(InsuranceError::AlreadyDecided)
+        );
+    }
+    assert_eq!(game.get_money(natural), Some(chips(110)));
+    assert_eq!(game.get_money(quitter), Some(chips(95)));
+    assert_eq!(game.get_insurance_bet(natural), None);
+    assert_eq!(game.get_insurance_bet(quitter), None);
+}
+
+#[test]
+fn early_surrender_is_offered_before_the_dealer_checks_for_blackjack() {
+    let options = GameOptions::default()
+        .with_dealer_peek(true)
+        .with_surrender(SurrenderRule::Early);
+    let game = Game::new(options, 74);
+    let stayer = game.join(chips(100));
+    let quitter = game.join(chips(100));
+    game.start_betting();
+    game.bet(stayer, chips(10)).unwrap();
+    game.bet(quitter, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 8),
+            card(Suit::Spades, 13),
+            card(Suit::Diamonds, 7),
+            card(Suit::Hearts, 9),
+            card(Suit::Clubs, 1),
+        ],
+    );
+    game.deal().unwrap();
+
+    // A ten-value up card opens the window without offering insurance
+    assert_eq!(game.state(), GameState::Insurance);
+    assert!(!game.is_insurance_offered());
+    assert_eq!(
+        game.take_insurance(quitter),
+        Err(InsuranceError::NotOffered)
+    );
+    assert!(game.surrender_allowed_now(quitter, 0));
+
+    assert_eq!(game.surrender(quitter, 0), Ok(chips(5)));
+    game.decline_insurance(stayer).unwrap();
+    assert!(game.all_insurance_decided());
+    assert!(game.finish_insurance().unwrap());
+
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(result.players[1].hands[0].outcome, HandOutcome::Surrendered);
+    assert_eq!(game.get_money(stayer), Some(chips(90)));
+    assert_eq!(game.get_money(quitter), Some(chips(95)));
+
+    // Late surrender waits for the dealer's check
+    let game = Game::new(GameOptions::default(), 74);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 9),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 7),
+            card(Suit::Clubs, 9),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.state(), GameState::Insurance);
+    assert_eq!(game.surrender(player, 0), Err(ActionError::InvalidState));
+}
+
+#[test]
+fn even_money_pays_a_natural_one_to_one_during_insurance() {
+    let game = Game::new(GameOptions::default(), 75);
+    let natural = game.join(chips(100));
+    let other = game.join(chips(100));
+    game.start_betting();
+    game.bet(natural, chips(10)).unwrap();
+    game.bet(other, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),
+            card(Suit::Clubs, 9),
+            card(Suit::Spades, 1),
+            card(Suit::Diamonds, 13),
+            card(Suit::Hearts, 8),
+            card(Suit::Clubs, 13),
+        ],
+    );
+    game.deal().unwrap();
+    assert_eq!(game.state(), GameState::Insurance);
+
+    assert_eq!(
+        game.take_even_money(other, 0),
+        Err(InsuranceError::NotBlackjack)
+    );
+    assert_eq!(game.take_even_money(natural, 0), Ok(chips(20)));
+    assert_eq!(game.get_money(natural), Some(chips(110)));
+    assert_eq!(game.immediate_payout(natural), Some(chips(20)));
+    assert_eq!(
+        game.take_even_money(natural, 0),
+        Err(InsuranceError::AlreadyDecided)
+    );
+
+    game.decline_insurance(other).unwrap();
+    assert!(game.finish_insurance().unwrap());
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(result.players[0].net, 10);
+    assert_eq!(game.get_money(natural), Some(chips(110)));
+    assert_eq!(game.get_money(other), Some(chips(90)));
+}
+
+#[test]
+fn five_card_charlie_wins_automatically() {
+    let game = Game::new(GameOptions::default().with_charlie(Some(5)), 76);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 2),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 3),
+            card(Suit::Diamonds, 9),
+            card(Suit::Hearts, 2),
+            card(Suit::Clubs, 2),
+            card(Suit::Spades, 2),
+        ],
+    );
+    game.deal().unwrap();
+
+    game.hit(player, 0).unwrap();
+    game.hit(player, 0).unwrap();
+    assert_eq!(
+        game.get_hands(player).unwrap()[0].status(),
+        HandStatus::Active
+    );
+    game.hit(player, 0).unwrap();
+    assert_eq!(
+        game.get_hands(player).unwrap()[0].status(),
+        HandStatus::Charlie
+    );
+    assert_eq!(game.state(), GameState::DealerTurn);
+
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Charlie);
+    assert_eq!(result.players[0].hands[0].payout, chips(20));
+    assert_eq!(game.get_money(player), Some(chips(110)));
+
+    assert_eq!(
+        GameOptions::default().with_charlie(Some(2)).validate(),
+        Err(OptionsError::InvalidCharlie)
+    );
+}
+
+#[test]
+fn max_cards_per_hand_must_hold_the_initial_deal() {
+    for max_cards in [0, 1] {
+        assert_eq!(
+            GameOptions::default()
+                .with_max_cards_per_hand(max_cards)
+                .validate(),
+            Err(OptionsError::InvalidMaxCards)
+        );
+    }
+    assert_eq!(
+        GameOptions::default().with_max_cards_per_hand(2).validate(),
+        Ok(())
+    );
+    assert_eq!(
+        GameOptions::default()
+            .with_initial_cards(3)
+            .with_max_cards_per_hand(2)
+            .validate(),
+        Err(OptionsError::InvalidMaxCards)
+    );
+}
+
+#[test]
+fn tie_rule_and_push_on_dealer_22_apply_at_showdown() {
+    fn settle(options: GameOptions, dealer: [u8; 3]) -> HandOutcome {
+        let game = Game::new(options, 77);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 10),
+                card(Suit::Clubs, dealer[0]),
+                card(Suit::Spades, 8),
+                card(Suit::Diamonds, dealer[1]),
+                card(Suit::Hearts, dealer[2]),
+            ],
+        );
+        game.deal().unwrap();
+        game.stand(player, 0).unwrap();
+        game.dealer_play().unwrap();
+        game.showdown().unwrap().players[0].hands[0].outcome
+    }
+
+    // Dealer 18 against the player's 18
+    assert_eq!(
+        settle(GameOptions::default(), [10, 8, 2]),
+        HandOutcome::Push
+    );
+    assert_eq!(
+        settle(GameOptions::default().with_ties(Tie::Dealer), [10, 8, 2]),
+        HandOutcome::Lose
+    );
+
+    // Dealer 16 draws to 22
+    assert_eq!(settle(GameOptions::default(), [10, 6, 6]), HandOutcome::Win);
+    let push_22 = GameOptions::default().with_push_on_dealer_22(true);
+    assert_eq!(settle(push_22.clone(), [10, 6, 6]), HandOutcome::Push);
+    assert_eq!(settle(push_22, [10, 6, 7]), HandOutcome::Win);
+}
+
+#[test]
+fn double_exposure_deals_hole_face_up_and_pays_blackjack_even_money() {
+    let game = Game::new(GameOptions::default().with_double_exposure(true), 78);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 1),
+            card(Suit::Clubs, 10),
+            card(Suit::Spades, 13),
+            card(Suit::Diamonds, 7),
+        ],
+    );
+    game.set_event_recording(true);
+    game.deal().unwrap();
+
+    let events = game.drain_events();
+    assert!(events.contains(&GameEvent::CardDealt {
+        recipient: CardRecipient::Dealer,
+        card: card(Suit::Diamonds, 7),
+        face_up: true,
+    }));
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, GameEvent::DealerRevealedHole { .. }))
+    );
+
+    assert_eq!(game.state(), GameState::DealerTurn);
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Blackjack);
+    assert_eq!(result.players[0].hands[0].payout, chips(20));
+    assert_eq!(game.get_money(player), Some(chips(110)));
+}
+
+#[test]
+fn blackjack_switch_swaps_second_cards_of_two_hands() {
+    let options = GameOptions::default().with_blackjack_switch(true);
+    let game = Game::new(options.clone(), 79);
+    let player = game.join(chips(100));
+    game.start_betting();
+
+    // The bet is placed on both hands
+    game.bet(player, chips(10)).unwrap();
+    assert_eq!(game.get_money(player), Some(chips(80)));
+    assert_eq!(game.cancel_bet(player), Ok(chips(20)));
+    game.bet(player, chips(10)).unwrap();
+
+    game.deal_from(&[
+        card(Suit::Hearts, 1),
+        card(Suit::Clubs, 10),
+        card(Suit::Spades, 9),
+        card(Suit::Diamonds, 6),
+        card(Suit::Hearts, 13),
+        card(Suit::Clubs, 8),
+    ])
+    .unwrap();
+    assert_eq!(game.get_hands(player).unwrap().len(), 2);
+    assert!(game.available_actions(player, 0).switch);
+
+    game.switch(player).unwrap();
+    assert_eq!(game.switch(player), Err(ActionError::CannotSwitch));
+    assert!(!game.available_actions(player, 0).switch);
+
+    // A 21 made by switching is not a blackjack
+    let hands = game.get_hands(player).unwrap();
+    assert_eq!(hands[0].value(), 21);
+    assert!(!hands[0].is_blackjack());
+    assert_eq!(hands[1].value(), 16);
+
+    game.stand(player, 0).unwrap();
+    game.stand(player, 1).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].hands[0].outcome, HandOutcome::Win);
+    assert_eq!(result.players[0].hands[1].outcome, HandOutcome::Lose);
+    assert_eq!(game.get_money(player), Some(chips(100)));
+
+    let replayed = Game::replay(options, 79, &game.action_log()).unwrap();
+    assert_eq!(replayed.state_fingerprint(), game.state_fingerprint());
+
+    let plain = Game::new(GameOptions::default(), 79);
+    let player = plain.join(chips(100));
+    plain.start_betting();
+    plain.bet(player, chips(10)).unwrap();
+    plain.deal().unwrap();
+    assert_eq!(plain.switch(player), Err(ActionError::CannotSwitch));
+}
+
+#[test]
+fn spanish_21_removes_tens_and_pays_bonus_21s() {
+    fn settle(options: GameOptions) -> (HandOutcome, Chips) {
+        let game = Game::new(options, 81);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(10)).unwrap();
+        game.deal_from(&[
+            card(Suit::Spades, 6),
+            card(Suit::Clubs, 13),
+            card(Suit::Spades, 7),
+            card(Suit::Diamonds, 5),
+            card(Suit::Spades, 8),
+            card(Suit::Hearts, 6),
+        ])
+        .unwrap();
+        game.hit(player, 0).unwrap();
+        game.stand(player, 0).unwrap();
+        game.dealer_play().unwrap();
+        let result = game.showdown().unwrap();
+        (
+            result.players[0].hands[0].outcome,
+            game.get_money(player).unwrap(),
+        )
+    }
+
+    let game = Game::new(GameOptions::spanish_21(), 80);
+    assert_eq!(game.cards_remaining(), 6 * SPANISH_DECK_SIZE);
+    while let Some(drawn) = game.draw_card() {
+        assert_ne!(drawn.rank, 10);
+    }
+
+    // Spaded 6-7-8 pays 3:1 and beats the dealer's 21
+    assert_eq!(
+        settle(GameOptions::default().with_spanish_bonuses(true)),
+        (HandOutcome::Bonus, chips(130))
+    );
+    assert_eq!(
+        settle(GameOptions::default()),
+        (HandOutcome::Push, chips(100))
+    );
+}
+
+#[test]
+fn perfect_pairs_side_bet_is_settled_from_the_opening_cards() {
+    let game = Game::new(GameOptions::default(), 82);
+    let player = game.join(chips(100));
+    game.start_betting();
+    assert_eq!(
+        game.place_side_bet(player, SideBetKind::PerfectPairs, chips(5)),
+        Err(BetError::NoBet)
+    );
+    game.bet(player, chips(10)).unwrap();
+    game.place_side_bet(player, SideBetKind::PerfectPairs, chips(5))
+        .unwrap();
+    assert_eq!(
+        game.place_side_bet(player, SideBetKind::PerfectPairs, chips(5)),
+        Err(BetError::SideBetAlreadyPlaced)
+    );
+    assert_eq!(game.get_money(player), Some(chips(85)));
+
+    // A colored pair of eights pays 12:1 even though the hand loses
+    game.deal_from(&[
+        card(Suit::Hearts, 8),
+        card(Suit::Clubs, 10),
+        card(Suit::Diamonds, 8),
+        card(Suit::Spades, 9),
+    ])
+    .unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+
+    let player_result = &result.players[0];
+    assert_eq!(player_result.hands[0].outcome, HandOutcome::Lose);
+    assert_eq!(
+        player_result.side_bets,
+        [SideBetResult {
+            kind: SideBetKind::PerfectPairs,
+            bet: chips(5),
+            payout: chips(65),
+        }]
+    );
+    assert_eq!(player_result.net, 50);
+    assert!(result.ledger.iter().any(|entry| {
+        entry.source == LedgerSource::SideBet(SideBetKind::PerfectPairs)
+            && entry.credit == chips(65)
+    }));
+    assert_eq!(game.get_money(player), Some(chips(150)));
+}
+
+#[test]
+fn lucky_ladies_and_lucky_lucky_pay_from_option_pay_tables() {
+    let options = GameOptions::default().with_lucky_ladies(LuckyLadies {
+        suited_twenty: Payout::new(8, 1),
+        ..LuckyLadies::default()
+    });
+    let game = Game::new(options, 83);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.place_side_bet(player, SideBetKind::LuckyLadies, chips(5))
+        .unwrap();
+    game.place_side_bet(player, SideBetKind::LuckyLucky, chips(5))
+        .unwrap();
+
+    // A suited soft 20, and 20 with the dealer's ten up
+    game.deal_from(&[
+        card(Suit::Hearts, 1),
+        card(Suit::Clubs, 10),
+        card(Suit::Hearts, 9),
+        card(Suit::Diamonds, 7),
+    ])
+    .unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+
+    let payouts: Vec<(SideBetKind, Chips)> = result.players[0]
+        .side_bets
+        .iter()
+        .map(|side_bet| (side_bet.kind, side_bet.payout))
+        .collect();
+    assert_eq!(
+        payouts,
+        [
+            (SideBetKind::LuckyLadies, chips(45)),
+            (SideBetKind::LuckyLucky, chips(15))
+        ]
+    );
+    assert_eq!(game.get_money(player), Some(chips(160)));
+
+    assert_eq!(
+        GameOptions::default()
+            .with_lucky_lucky(LuckyLucky {
+                twenty_one: Payout::new(1, 0),
+                ..LuckyLucky::default()
+            })
+            .validate(),
+        Err(OptionsError::InvalidPayout)
+    );
+}
+
+#[test]
+fn buster_side_bet_pays_by_dealer_bust_card_count_at_showdown() {
+    let game = Game::new(GameOptions::default(), 84);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(10)).unwrap();
+    game.place_side_bet(player, SideBetKind::Buster, chips(5))
+        .unwrap();
+    game.place_side_bet(player, SideBetKind::PerfectPairs, chips(5))
+        .unwrap();
+
+    // The dealer busts with five cards
+    game.deal_from(&[
+        card(Suit::Hearts, 10),
+        card(Suit::Spades, 2),
+        card(Suit::Clubs, 8),
+        card(Suit::Hearts, 4),
+        card(Suit::Clubs, 5),
+        card(Suit::Diamonds, 3),
+        card(Suit::Spades, 10),
+    ])
+    .unwrap();
+    game.stand(player, 0).unwrap();
+    game.dealer_play().unwrap();
+    let result = game.showdown().unwrap();
+
+    assert!(result.dealer_bust);
+    assert_eq!(
+        result.players[0].side_bets,
+        [
+            SideBetResult {
+                kind: SideBetKind::PerfectPairs,
+                bet: chips(5),
+                payout: chips(0),
+            },
+            SideBetResult {
+                kind: SideBetKind::Buster,
+                bet: chips(5),
+                payout: chips(25),
+            },
+        ]
+    );
+    assert_eq!(game.get_money(player), Some(chips(125)));
+}
+
+#[test]
+fn insurance_for_less_is_settled_at_two_to_one() {
+    let game = Game::new(GameOptions::default(), 85);
+    let player = game.join(chips(100));
+    game.start_betting();
+    game.bet(player, chips(20)).unwrap();
+    game.deal_from(&[
+        card(Suit::Hearts, 10),
+        card(Suit::Spades, 1),
+        card(Suit::Clubs, 8),
+        card(Suit::Diamonds, 13),
+    ])
+    .unwrap();
+    assert_eq!(game.state(), GameState::Insurance);
+
+    assert_eq!(
+        game.take_insurance_amount(player, chips(0)),
+        Err(InsuranceError::InvalidAmount)
+    );
+    assert_eq!(
+        game.take_insurance_amount(player, chips(11)),
+        Err(InsuranceError::InvalidAmount)
+    );
+    assert_eq!(game.take_insurance_amount(player, chips(4)), Ok(chips(4)));
+    assert_eq!(game.get_insurance_bet(player), Some(chips(4)));
+
+    game.finish_insurance().unwrap();
+    let result = game.showdown().unwrap();
+    assert_eq!(result.players[0].insurance_payout, chips(12));
+    assert_eq!(game.get_money(player), Some(chips(88)));
+}
+
+#[test]
+fn insurance_on_an_odd_bet_is_rounded_by_rounding_insurance() {
+    fn insured(mode: RoundingMode) -> (Chips, Chips) {
+        let game = Game::new(GameOptions::default().with_rounding_insurance(mode), 86);
+        let player = game.join(chips(100));
+        game.start_betting();
+        game.bet(player, chips(15)).unwrap();
+        game.deal_from(&[
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 1),
+            card(Suit::Clubs, 8),
+            card(Suit::Diamonds, 13),
+        ])
+        .unwrap();
+        let insurance = game.take_insurance(player).unwrap();
+        game.finish_insurance().unwrap();
+        let result = game.showdown().unwrap();
+        (insurance, result.players[0].insurance_payout)
+    }
+
+    assert_eq!(insured(RoundingMode::Down), (chips(7), chips(21)));
+    assert_eq!(insured(RoundingMode::Up), (chips(8), chips(24)));
+}
+
+#[test]
+fn six_to_five_blackjack_is_paid_with_exact_integer_math() {
+    fn blackjack_payout(bet: Chips, mode: RoundingMode) -> Chips {
+        let options = GameOptions::default()
+            .with_insurance(false)
+            .with_penetration(0.0)
+            .with_blackjack_pays(Payout::new(12, 10))
+            .with_rounding_blackjack(mode);
+        let game = Game::new(options, 87);
+        let player = game.join(chips(100));
+        set_deck_from_draws(
+            &game,
+            &[
+                card(Suit::Hearts, 1),
+                card(Suit::Spades, 9),
+                card(Suit::Clubs, 13),
+                card(Suit::Diamonds, 8),
+            ],
+        );
+        let result = game
+            .play_round(&[(player, bet)], |_, _, _| PlayerDecision::Stand)
+            .unwrap();
+        result.players[0].hands[0].payout
+    }
+
+    assert_eq!(Payout::new(12, 10), Payout::new(6, 5));
+    assert_eq!(blackjack_payout(chips(25), RoundingMode::Down), chips(55));
+    // 6:5 on 12 wins 14.4
+    assert_eq!(blackjack_payout(chips(12), RoundingMode::Down), chips(26));
+    assert_eq!(blackjack_payout(chips(12), RoundingMode::Up), chips(27));
+    assert_eq!(
+        blackjack_payout(chips(12), RoundingMode::Nearest),
+        chips(26)
+    );
+}
+
+#[test]
+fn money_beyond_u32_is_bet_and_settled_exactly() {
+    let big = chips(10_000_000_000);
+    let options = GameOptions::default().with_penetration(0.0);
+    let game = Game::new(options, 88);
+    let player = game.join(big);
+    game.start_betting();
+    assert_eq!(
+        game.bet(player, Chips::MAX).unwrap_err(),
+        BetError::InsufficientFunds
+    );
+    assert_eq!(game.get_money(player), Some(big));
+
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Clubs, 10),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    let result = game
+        .play_round(&[(player, big)], |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    assert_eq!(result.players[0].net, 10_000_000_000);
+    assert_eq!(game.get_money(player), Some(chips(20_000_000_000)));
+}
+
+#[test]
+fn winnings_near_the_chip_limit_saturate_instead_of_panicking() {
+    let options = GameOptions::default().with_penetration(0.0);
+    let game = Game::new(options, 88);
+    let player = game.join(chips(u64::MAX - 5));
+    game.start_betting();
+    set_deck_from_draws(
+        &game,
+        &[
+            card(Suit::Hearts, 10),
+            card(Suit::Spades, 9),
+            card(Suit::Clubs, 10),
+            card(Suit::Diamonds, 8),
+        ],
+    );
+    let result = game
+        .play_round(&[(player, chips(10))], |_, _, _| PlayerDecision::Stand)
+        .unwrap();
+    assert_eq!(result.players[0].hands[0].payout, chips(20));
+    assert_eq!(game.get_money(player), Some(Chips::MAX));
+}
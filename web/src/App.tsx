@@ -25,6 +25,7 @@ type JsDealer = {
   is_blackjack: boolean;
   is_bust: boolean;
   hole_revealed: boolean;
+  will_hit: boolean;
 };
 
 type JsTurn = {
@@ -61,6 +62,7 @@ type JsPlayerResult = {
   net: number;
   insurance_bet: number;
   insurance_payout: number;
+  insurance_outcome: string | null;
 };
 
 type JsRoundResult = {
@@ -1,6 +1,6 @@
 use bjrs::{
-    Card, Game, GameOptions, GameState, Hand, HandOutcome, HandStatus, PlayerResult, RoundResult,
-    Suit,
+    Card, Chips, Game, GameOptions, GameState, Hand, HandOutcome, HandStatus, PlayerResult,
+    RoundResult, Suit,
 };
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
@@ -31,7 +31,7 @@ impl WasmGame {
             return id as u32;
         }
 
-        let id = self.game.join(money as usize);
+        let id = self.game.join(Chips::new(u64::from(money)));
         self.player_id = Some(id);
         id as u32
     }
@@ -47,7 +47,7 @@ impl WasmGame {
     pub fn bet(&self, amount: u32) -> Result<(), JsValue> {
         let player_id = self.require_player()?;
         self.game
-            .bet(player_id, amount as usize)
+            .bet(player_id, Chips::new(u64::from(amount)))
             .map_err(js_err)
     }
 
@@ -89,7 +89,7 @@ impl WasmGame {
         let player_id = self.require_player()?;
         self.game
             .surrender(player_id, hand_index as usize)
-            .map(|refund| refund as u32)
+            .map(|refund| refund.get() as u32)
             .map_err(js_err)
     }
 
@@ -97,15 +97,13 @@ impl WasmGame {
         let player_id = self.require_player()?;
         self.game
             .take_insurance(player_id)
-            .map(|bet| bet as u32)
+            .map(|bet| bet.get() as u32)
             .map_err(js_err)
     }
 
     pub fn decline_insurance(&self) -> Result<(), JsValue> {
         let player_id = self.require_player()?;
-        self.game
-            .decline_insurance(player_id)
-            .map_err(js_err)
+        self.game.decline_insurance(player_id).map_err(js_err)
     }
 
     pub fn finish_insurance(&self) -> Result<bool, JsValue> {
@@ -131,23 +129,30 @@ impl WasmGame {
         let player_id = self.player_id;
 
         let (money, bet, hands, insurance_bet) = if let Some(id) = player_id {
-            let money = self.game.get_money(id).map(|value| value as u32);
-            let bet = self.game.get_bet(id).map(|value| value as u32);
+            let money = self.game.get_money(id).map(|value| value.get() as u32);
+            let bet = self.game.get_bet(id).map(|value| value.get() as u32);
             let hands = self
                 .game
                 .get_hands(id)
                 .unwrap_or_default()
                 .into_iter()
                 .enumerate()
-                .map(|(index, hand)| JsHand::from_hand(index as u32, &hand))
+                .map(|(index, hand)| {
+                    let can_split = self.game.split_deny_reason(id, index).is_none();
+                    JsHand::from_hand(index as u32, &hand, can_split)
+                })
                 .collect();
-            let insurance_bet = self.game.get_insurance_bet(id).map(|value| value as u32);
+            let insurance_bet = self
+                .game
+                .get_insurance_bet(id)
+                .map(|value| value.get() as u32);
             (money, bet, hands, insurance_bet)
         } else {
             (None, None, Vec::new(), None)
         };
 
-        let dealer = JsDealer::from(self.game.get_dealer_hand());
+        let dealer =
+            JsDealer::from_hand(self.game.get_dealer_hand(), self.game.dealer_should_hit());
         let turn = self.game.current_turn();
         let current_turn = self.game.current_player().map(|player_id| JsTurn {
             player_id: player_id as u32,
@@ -217,16 +222,16 @@ struct JsHand {
 }
 
 impl JsHand {
-    fn from_hand(index: u32, hand: &Hand) -> Self {
+    fn from_hand(index: u32, hand: &Hand, can_split: bool) -> Self {
         Self {
             index,
             cards: hand.cards().iter().copied().map(card_to_js).collect(),
             value: hand.value(),
             is_soft: hand.is_soft(),
             status: hand_status_to_str(hand.status()),
-            bet: hand.bet() as u32,
+            bet: hand.bet().get() as u32,
             from_split: hand.is_from_split(),
-            can_split: hand.can_split(),
+            can_split,
         }
     }
 }
@@ -240,10 +245,11 @@ struct JsDealer {
     is_blackjack: bool,
     is_bust: bool,
     hole_revealed: bool,
+    will_hit: bool,
 }
 
-impl From<bjrs::DealerHand> for JsDealer {
-    fn from(dealer: bjrs::DealerHand) -> Self {
+impl JsDealer {
+    fn from_hand(dealer: bjrs::DealerHand, will_hit: bool) -> Self {
         let hole_revealed = dealer.is_hole_revealed();
         let cards = dealer
             .cards()
@@ -266,6 +272,7 @@ impl From<bjrs::DealerHand> for JsDealer {
             is_blackjack: dealer.is_blackjack(),
             is_bust: dealer.is_bust(),
             hole_revealed,
+            will_hit,
         }
     }
 }
@@ -281,7 +288,11 @@ struct JsRoundResult {
 impl From<RoundResult> for JsRoundResult {
     fn from(result: RoundResult) -> Self {
         Self {
-            players: result.players.into_iter().map(JsPlayerResult::from).collect(),
+            players: result
+                .players
+                .into_iter()
+                .map(JsPlayerResult::from)
+                .collect(),
             dealer_value: result.dealer_value,
             dealer_bust: result.dealer_bust,
             dealer_blackjack: result.dealer_blackjack,
@@ -297,6 +308,7 @@ struct JsPlayerResult {
     net: i32,
     insurance_bet: u32,
     insurance_payout: u32,
+    insurance_outcome: Option<&'static str>,
 }
 
 impl From<PlayerResult> for JsPlayerResult {
@@ -304,10 +316,11 @@ impl From<PlayerResult> for JsPlayerResult {
         Self {
             player_id: result.player_id as u32,
             hands: result.hands.into_iter().map(JsHandResult::from).collect(),
-            total_payout: result.total_payout as u32,
+            total_payout: result.total_payout.get() as u32,
             net: result.net as i32,
-            insurance_bet: result.insurance_bet as u32,
-            insurance_payout: result.insurance_payout as u32,
+            insurance_bet: result.insurance_bet.get() as u32,
+            insurance_payout: result.insurance_payout.get() as u32,
+            insurance_outcome: result.insurance_outcome.map(outcome_to_str),
         }
     }
 }
@@ -327,8 +340,8 @@ impl From<bjrs::HandResult> for JsHandResult {
         Self {
             hand_index: result.hand_index as u32,
             outcome: outcome_to_str(result.outcome),
-            bet: result.bet as u32,
-            payout: result.payout as u32,
+            bet: result.bet.get() as u32,
+            payout: result.payout.get() as u32,
             player_value: result.player_value,
             dealer_value: result.dealer_value,
         }
@@ -370,6 +383,7 @@ fn hand_status_to_str(status: HandStatus) -> &'static str {
         HandStatus::Bust => "Bust",
         HandStatus::Blackjack => "Blackjack",
         HandStatus::Surrendered => "Surrendered",
+        HandStatus::Charlie => "Charlie",
     }
 }
 
@@ -380,6 +394,8 @@ fn outcome_to_str(outcome: HandOutcome) -> &'static str {
         HandOutcome::Push => "Push",
         HandOutcome::Blackjack => "Blackjack",
         HandOutcome::Surrendered => "Surrendered",
+        HandOutcome::Charlie => "Charlie",
+        HandOutcome::Bonus => "Bonus",
     }
 }
 